@@ -77,5 +77,8 @@ pub trait Complex {
 mod complexes;
 pub use complexes::*;
 
+mod simplicial;
+pub use simplicial::SimplicialComplex;
+
 // In this package, I'll regard simplex and complex as totally different things
 
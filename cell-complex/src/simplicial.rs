@@ -0,0 +1,101 @@
+use super::*;
+
+/// an abstract simplicial complex on the vertices `0..vertices`, kept by its facets
+///
+/// the faces are increasing lists of vertices, every subset of a face is a face
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct SimplicialComplex {
+	vertices : usize,
+	facets : Vec<Vec<usize>>,
+}
+impl SimplicialComplex {
+	/// the complex generated by `faces`, which need not be maximal nor sorted
+	pub fn from(vertices : usize, faces : Vec<Vec<usize>>) -> SimplicialComplex {
+		let mut faces : Vec<Vec<usize>> = faces.into_iter().map(|mut f| {
+			f.sort();
+			f.dedup();
+			if let Some(v) = f.last().filter(|v| **v >= vertices) {
+				panic!("invalid vertex {}: there are only {} vertices", v, vertices)
+			}
+			f
+		}).collect();
+		// the larger faces first, so that every face is compared with the faces which may contain it
+		faces.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+		let mut facets : Vec<Vec<usize>> = Vec::new();
+		for f in faces {
+			if !facets.iter().any(|g| is_subset(&f, g)) {
+				facets.push(f);
+			}
+		}
+		facets.sort();
+		SimplicialComplex { vertices, facets }
+	}
+
+	pub fn vertices(&self) -> usize {
+		self.vertices
+	}
+
+	/// the maximal faces
+	pub fn facets(&self) -> &[Vec<usize>] {
+		&self.facets
+	}
+
+	pub fn contains(&self, face : &[usize]) -> bool {
+		let mut face = face.to_vec();
+		face.sort();
+		self.facets.iter().any(|g| is_subset(&face, g))
+	}
+
+	/// all non-empty faces, sorted
+	pub fn faces(&self) -> Vec<Vec<usize>> {
+		let mut faces : Vec<Vec<usize>> = self.facets.iter().flat_map(|f| {
+			(1..1usize << f.len()).map(move |mask| f.iter().enumerate().filter(|(i, _)| mask >> i & 1 == 1).map(|(_, v)| *v).collect())
+		}).collect();
+		faces.sort();
+		faces.dedup();
+		faces
+	}
+
+	/// `f_i`, the number of faces of dimension `i`
+	pub fn f_vector(&self) -> Vec<usize> {
+		let mut f = Vec::new();
+		for face in self.faces() {
+			if f.len() < face.len() {
+				f.resize(face.len(), 0);
+			}
+			f[face.len() - 1] += 1;
+		}
+		f
+	}
+
+	/// the Euler characteristic minus one, where the empty face counts in dimension `-1`
+	pub fn reduced_euler(&self) -> isize {
+		self.euler() - 1
+	}
+}
+impl Complex for SimplicialComplex {
+	fn component(&self) -> Component {
+		Component::from(self.f_vector())
+	}
+}
+
+/// for increasing lists
+fn is_subset(a : &[usize], b : &[usize]) -> bool {
+	let mut iter = b.iter();
+	a.iter().all(|x| iter.any(|y| y == x))
+}
+
+#[test] fn simplicial_complex() {
+	// the boundary of a triangle, with a redundant edge
+	let circle = SimplicialComplex::from(3, vec![vec![1, 0], vec![1, 2], vec![0, 2], vec![0]]);
+	assert_eq!(circle.facets(), &[vec![0, 1], vec![0, 2], vec![1, 2]]);
+	assert_eq!(circle.f_vector(), vec![3, 3]);
+	assert_eq!(circle.euler(), 0);
+	assert!(circle.contains(&[2, 1]) && !circle.contains(&[0, 1, 2]));
+
+	let triangle = SimplicialComplex::from(3, vec![vec![0, 1, 2], vec![0, 1]]);
+	assert_eq!(triangle.facets().len(), 1);
+	assert_eq!(triangle.f_vector(), vec![3, 3, 1]);
+	assert_eq!(triangle.reduced_euler(), 0);
+	assert_eq!(triangle.dim(), Ok(2));
+}
@@ -0,0 +1,106 @@
+//! complex floating point numbers, for numerical computations next to the exact ones
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// `re + im i`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct C64 {
+	pub re : f64,
+	pub im : f64,
+}
+impl C64 {
+	pub const ZERO : C64 = C64 { re : 0.0, im : 0.0 };
+	pub const ONE : C64 = C64 { re : 1.0, im : 0.0 };
+	pub const I : C64 = C64 { re : 0.0, im : 1.0 };
+
+	pub fn new(re : f64, im : f64) -> C64 {
+		C64 { re, im }
+	}
+
+	pub fn from_polar(r : f64, theta : f64) -> C64 {
+		C64 { re : r * theta.cos(), im : r * theta.sin() }
+	}
+
+	/// |z|, without overflow in the intermediate squares
+	pub fn abs(&self) -> f64 {
+		self.re.hypot(self.im)
+	}
+
+	pub fn arg(&self) -> f64 {
+		self.im.atan2(self.re)
+	}
+
+	pub fn conj(&self) -> C64 {
+		C64 { re : self.re, im : -self.im }
+	}
+
+	pub fn is_finite(&self) -> bool {
+		self.re.is_finite() && self.im.is_finite()
+	}
+}
+impl From<f64> for C64 {
+	fn from(re : f64) -> C64 {
+		C64 { re, im : 0.0 }
+	}
+}
+impl Add for C64 {
+	type Output = C64;
+	fn add(self, rhs : Self) -> Self::Output {
+		C64 { re : self.re + rhs.re, im : self.im + rhs.im }
+	}
+}
+impl Sub for C64 {
+	type Output = C64;
+	fn sub(self, rhs : Self) -> Self::Output {
+		C64 { re : self.re - rhs.re, im : self.im - rhs.im }
+	}
+}
+impl Neg for C64 {
+	type Output = C64;
+	fn neg(self) -> Self::Output {
+		C64 { re : -self.re, im : -self.im }
+	}
+}
+impl Mul for C64 {
+	type Output = C64;
+	fn mul(self, rhs : Self) -> Self::Output {
+		C64 { re : self.re * rhs.re - self.im * rhs.im, im : self.re * rhs.im + self.im * rhs.re }
+	}
+}
+impl Mul<f64> for C64 {
+	type Output = C64;
+	fn mul(self, rhs : f64) -> Self::Output {
+		C64 { re : self.re * rhs, im : self.im * rhs }
+	}
+}
+impl Div for C64 {
+	type Output = C64;
+	/// Smith's algorithm, to avoid overflow in `|rhs|^2`
+	fn div(self, rhs : Self) -> Self::Output {
+		if rhs.re.abs() >= rhs.im.abs() {
+			let (r, d) = (rhs.im / rhs.re, rhs.re + rhs.im * (rhs.im / rhs.re));
+			C64 { re : (self.re + self.im * r) / d, im : (self.im - self.re * r) / d }
+		} else {
+			let (r, d) = (rhs.re / rhs.im, rhs.im + rhs.re * (rhs.re / rhs.im));
+			C64 { re : (self.re * r + self.im) / d, im : (self.im * r - self.re) / d }
+		}
+	}
+}
+impl fmt::Display for C64 {
+	fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.im < 0.0 {
+			write!(f, "{} - {}i", self.re, -self.im)
+		} else {
+			write!(f, "{} + {}i", self.re, self.im)
+		}
+	}
+}
+#[test] fn complex_arithmetic() {
+	let (a, b) = (C64::new(1.0, 2.0), C64::new(3.0, -1.0));
+	assert_eq!(a * b, C64::new(5.0, 5.0));
+	assert!(((a * b) / b - a).abs() < 1e-15);
+	assert_eq!(C64::I * C64::I, -C64::ONE);
+	assert_eq!(C64::new(3.0, 4.0).abs(), 5.0);
+	assert_eq!(format!("{}", a.conj()), "1 - 2i");
+}
@@ -1,31 +1,106 @@
 use std::collections::{HashMap, HashSet};
-use std::ops::{Add, Deref, DerefMut, Div, Mul};
-use std::fmt::Debug;
+use std::ops::{Add, Deref, DerefMut, Div, Mul, Neg, Sub};
+use std::fmt::{self, Debug};
 use alias::{BasicObject, BasicObjectCopy};
 
 mod just_for_fun;
 
-pub trait AddGroup : BasicObjectCopy + Add {
+pub mod poly;
+pub use poly::{UniPolynomial, MultiPolynomial};
+pub mod numeric;
+pub use numeric::{Numeric, Interval};
+pub mod complex;
+pub use complex::C64;
+pub mod monomial_ideal;
+pub use monomial_ideal::{MonomialIdeal, HilbertSeries};
+pub mod multilinear;
+pub use multilinear::{ExteriorAlgebra, SymmetricAlgebra};
+
+pub trait AddGroup : BasicObject + Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self> {
 	fn zero() -> Self;
 	fn is_zero(&self) -> bool {
 		self == &Self::zero()
 	}
+	/// `n * self`, by double-and-add
+	fn times(&self, mut n : usize) -> Self {
+		let mut base = self.clone();
+		let mut acc = Self::zero();
+		while n > 0 {
+			if n & 1 == 1 {
+				acc = acc + base.clone();
+			}
+			base = base.clone() + base;
+			n >>= 1;
+		}
+		acc
+	}
 }
 pub trait Ring : AddGroup + Mul<Output = Self> {
 	fn one() -> Self;
 	fn is_one(&self) -> bool {
 		self == &Self::one()
 	}
-	fn pow(&self, n : usize) -> Self {
-		(0..n).map(|e| {eprintln!("pow: {:?}", e); e}).fold(Self::one(), |acc, _x| (acc * (*self)))
+	/// by square-and-multiply
+	fn pow(&self, mut n : usize) -> Self {
+		let mut base = self.clone();
+		let mut acc = Self::one();
+		while n > 0 {
+			if n & 1 == 1 {
+				acc = acc * base.clone();
+			}
+			n >>= 1;
+			if n > 0 {
+				base = base.clone() * base;
+			}
+		}
+		acc
+	}
+	/// the coefficients of the product of two polynomials with coefficients `lhs` and `rhs`,
+	/// override it if there is a faster multiplication for this ring, see `poly::fast_mul`
+	fn convolution(lhs : &[Self], rhs : &[Self]) -> Vec<Self> {
+		poly::fast_mul::karatsuba(lhs, rhs)
+	}
+}
+/// a (commutative) ring without zero divisors, where we can tell whether a quotient exists
+pub trait IntegralDomain : Ring {
+	/// return `self / rhs` if `rhs` divides `self` exactly
+	fn exact_div(&self, rhs : &Self) -> Option<Self>;
+}
+pub trait Field : IntegralDomain + Div<Output = Self> {
+	fn inv(&self) -> Self {
+		Self::one() / self.clone()
 	}
 }
-pub trait Field : Ring + Div {}
+/// a field with a total order compatible with its arithmetic, as the simplex method needs
+pub trait OrderedField : Field + Ord {}
 pub trait ScaMul<R : Ring> {
     fn sca_mul(self, sca: R) -> Self;
 }
 pub trait Module<R : Ring> : AddGroup + ScaMul<R> {}
 pub trait Algebra<R : Ring> : Ring + Module<R> {}
+/// componentwise structures on structs, see `general_marco`
+pub use general_marco::{AddGroup, Ring, Module};
+/// a `MultiPolynomial<QQ>` literal such as `poly!(vt; 3*x^2*y - 1/2 + z)`
+///
+/// with a `VarTable` the variables are looked up by name when it runs, so it returns a `Result`,
+/// with a list of names `poly!([x, y, z]; ...)` the `n`-th name is the variable with index `n`,
+/// and an unknown variable, or a coefficient overflowing `i128` when the like terms are collected, is a compile error
+/// ```
+/// use general::algebra::{poly, VarTable, MultiPolynomial, num::QQ};
+/// let mut vt = VarTable::new();
+/// vt.extend(["x", "y", "z"].map(String::from));
+/// let f = poly!(vt; 3*x^2*y - 1/2 + z).unwrap();
+/// assert_eq!(f, poly!([x, y, z]; -1/2 + z + 3*y*x^2));
+/// assert_eq!(f.coef(&vec![2, 1]), QQ::from_pair(3, 1));
+/// assert!(poly!(vt; x + w).is_err());
+/// ```
+/// ```compile_fail
+/// general::algebra::poly!([x, y]; x + w);
+/// ```
+/// ```compile_fail
+/// general::algebra::poly!([x]; 1/170141183460469231731687303715884105727*x + 1/170141183460469231731687303715884105726*x);
+/// ```
+pub use general_marco::poly;
 
 #[derive(Debug, Clone, PartialEq, Eq, BasicObject, Hash)]
 pub struct VarTable(Vec<String>);
@@ -44,7 +119,30 @@ impl VarTable {
 	pub fn new() -> VarTable {
 		VarTable(Vec::new())
 	}
+
+	/// the exponent vector of `x^a * y^b ...`, given by the names and powers of the variables, as `poly!` needs
+	pub fn exponent(&self, powers : &[(&str, usize)]) -> Result<Vec<usize>, UndefinedVariable> {
+		let mut e = Vec::new();
+		for &(name, n) in powers {
+			let index = self.iter().position(|x| x == name).ok_or_else(|| UndefinedVariable(name.to_string()))?;
+			if e.len() <= index {
+				e.resize(index + 1, 0);
+			}
+			e[index] += n;
+		}
+		Ok(e)
+	}
+}
+
+/// a name which is not in the `VarTable`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndefinedVariable(pub String);
+impl fmt::Display for UndefinedVariable {
+	fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Variable {} is not defined yet", self.0)
+	}
 }
+impl std::error::Error for UndefinedVariable {}
 
 #[derive(Debug, Clone, PartialEq, Eq, BasicObject, Copy, BasicObjectCopy, Hash)]
 pub struct Var<'a> {
@@ -61,8 +159,8 @@ impl<'a> Var<'a> {
 	///
 	/// # Example
 	/// ```
-	/// use general::num::VarTable;
-	/// use general::num::Var;
+	/// use general::algebra::VarTable;
+	/// use general::algebra::Var;
 	/// let mut vt = VarTable::new();
 	/// let v = Var::new(String::from("x"), &mut vt);
 	///
@@ -89,8 +187,8 @@ impl<'a> Var<'a> {
 	///
 	/// # Example
 	/// ```
-	/// use general::num::VarTable;
-	/// use general::num::Var;
+	/// use general::algebra::VarTable;
+	/// use general::algebra::Var;
 	/// let mut vt = VarTable::new();
 	/// vt.push(String::from("x"));
 	/// let v = Var::from_index(0, &vt);
@@ -113,8 +211,8 @@ impl<'a> Var<'a> {
 	///
 	/// # Example
 	/// ```
-	/// use general::num::VarTable;
-	/// use general::num::Var;
+	/// use general::algebra::VarTable;
+	/// use general::algebra::Var;
 	/// let mut vt = VarTable::new();
 	/// vt.push(String::from("x"));
 	/// let v = Var::from_name(String::from("x"), &vt);
@@ -122,10 +220,26 @@ impl<'a> Var<'a> {
 	/// assert_eq!(v.name(), String::from("x"));
 	/// ```
 	pub fn from_name(name : String, vt : &VarTable) -> Var {
-		if let Some((index, _)) = vt.iter().enumerate().find(|(_, x)| **x == name) {
-			Var { index, source : vt }
-		} else {
-			panic!("Variable {name} is not defined yet")
+		match Var::try_from_name(name, vt) {
+			Ok(var) => var,
+			Err(e) => panic!("{}", e),
+		}
+	}
+	/// Get a `Var` instance from a `VarTable` instance by **name**, if it is defined.
+	///
+	/// # Example
+	/// ```
+	/// use general::algebra::{VarTable, Var, UndefinedVariable};
+	/// let mut vt = VarTable::new();
+	/// vt.push(String::from("x"));
+	///
+	/// assert!(Var::try_from_name(String::from("x"), &vt).is_ok());
+	/// assert_eq!(Var::try_from_name(String::from("y"), &vt), Err(UndefinedVariable(String::from("y"))));
+	/// ```
+	pub fn try_from_name(name : String, vt : &VarTable) -> Result<Var<'_>, UndefinedVariable> {
+		match vt.iter().position(|x| *x == name) {
+			Some(index) => Ok(Var { index, source : vt }),
+			None => Err(UndefinedVariable(name)),
 		}
 	}
 
@@ -172,65 +286,201 @@ pub trait Polynomial<'a, R : Ring, A : Algebra<R>> : BasicObject {
 pub mod num {
 	use crate::algebra::*;
 
-	#[derive(Debug, Clone, Copy, PartialEq, Eq, BasicObject, BasicObjectCopy)]
-	pub struct ZZ {value : u128}
+	#[derive(Debug, Clone, Copy, PartialEq, Eq, BasicObject, BasicObjectCopy, Hash)]
+	pub struct ZZ {value : i128}
 	impl Deref for ZZ {
-		type Target = u128;
+		type Target = i128;
 		fn deref(&self) -> &Self::Target {
 			&self.value
 		}
 	}
+	impl ZZ {
+		pub fn from(value : i128) -> ZZ {
+			ZZ { value }
+		}
+	}
 
-	impl Add for ZZ { type Output = ZZ; fn add(self, other: ZZ) -> ZZ { ZZ { value: *self + *other } } }
+	impl Add for ZZ { type Output = ZZ; fn add(self, other: ZZ) -> ZZ { ZZ { value: self.checked_add(*other).expect("ZZ: overflow") } } }
+	impl Sub for ZZ { type Output = ZZ; fn sub(self, other: ZZ) -> ZZ { ZZ { value: self.checked_sub(*other).expect("ZZ: overflow") } } }
+	impl Neg for ZZ { type Output = ZZ; fn neg(self) -> ZZ { ZZ { value: self.checked_neg().expect("ZZ: overflow") } } }
 	impl AddGroup for ZZ {
 		fn zero() -> ZZ { ZZ {value : 0} }
 	}
 
-	impl Mul for ZZ { type Output = ZZ; fn mul(self, other: ZZ) -> ZZ { ZZ { value: *self * *other } } }
+	impl Mul for ZZ { type Output = ZZ; fn mul(self, other: ZZ) -> ZZ { ZZ { value: self.checked_mul(*other).expect("ZZ: overflow") } } }
 	impl Ring for ZZ {
 		fn one() -> ZZ { ZZ {value : 1} }
+		fn convolution(lhs : &[ZZ], rhs : &[ZZ]) -> Vec<ZZ> {
+			poly::fast_mul::zz_convolution(lhs, rhs)
+		}
 	}
+	impl IntegralDomain for ZZ {
+		fn exact_div(&self, rhs : &Self) -> Option<Self> {
+			if rhs.is_zero() || self.checked_rem(**rhs).expect("ZZ: overflow") != 0 {
+				None
+			} else {
+				Some(ZZ { value: **self / **rhs })
+			}
+		}
+	}
+
+	#[test] fn zz_overflow() {
+		let message = |f : fn()| {
+			let payload = std::panic::catch_unwind(f).unwrap_err();
+			payload.downcast_ref::<&str>().map(|m| m.to_string()).or_else(|| payload.downcast_ref::<String>().cloned())
+		};
+		let overflow = Some(String::from("ZZ: overflow"));
+		assert_eq!(message(|| { let _ = -ZZ::from(i128::MIN); }), overflow);
+		assert_eq!(message(|| { let _ = ZZ::from(i128::MIN).exact_div(&ZZ::from(-1)); }), overflow);
+		assert_eq!(message(|| { let _ = gcd(i128::MIN, 0); }), overflow);
+		assert_eq!(message(|| { let _ = -QQ::from_pair(i128::MIN, 1); }), Some(String::from("QQ: overflow")));
+		assert_eq!(-ZZ::from(i128::MAX), ZZ::from(i128::MIN + 1));
+		assert_eq!(ZZ::from(i128::MIN).exact_div(&ZZ::from(2)), Some(ZZ::from(i128::MIN / 2)));
+	}
+	impl Div for ZZ { type Output = QQ; fn div(self, other: ZZ) -> QQ { QQ::from_pair(*self, *other) } }
 
-	impl Div for ZZ { type Output = QQ; fn div(self, other: ZZ) -> QQ { QQ { inner: (*self, *other) } } }
+	impl Numeric for ZZ {
+		/// `as` rounds to the nearest, ties to even
+		fn numeric(&self) -> f64 { self.value as f64 }
+		fn enclosure(&self) -> Interval {
+			Interval::from_rounded(self.numeric(), self.value.unsigned_abs() <= (1 << 53))
+		}
+	}
 
-	// impl Numeric for ZZ {fn numeric(&self) -> f64 { self.value.into() }}
+	pub(crate) fn gcd(mut a : i128, mut b : i128) -> i128 {
+		while b != 0 {
+			(a, b) = (b, a % b);
+		}
+		a.checked_abs().expect("ZZ: overflow")
+	}
 
+	/// always kept in lowest terms with a positive denominator
 	#[derive(Debug, Clone, Eq, BasicObject, Copy, BasicObjectCopy)]
 	pub struct QQ {
-		inner : (u128, u128)
+		inner : (i128, i128)
 	}
 	impl QQ {
-		pub fn from_pair(a : u128, b : u128) -> QQ {
+		pub fn from_pair(a : i128, b : i128) -> QQ {
 			if b == 0 {
 				panic!("0 cannot be divisor")
 			} else {
-				QQ { inner: (a, b) }
+				let d = gcd(a, b) * b.signum();
+				QQ { inner: (a / d, b / d) }
 			}
 		}
+		pub fn numerator(&self) -> i128 {
+			self.inner.0
+		}
+		pub fn denominator(&self) -> i128 {
+			self.inner.1
+		}
 	}
 	impl Add for QQ {
 		type Output = QQ;
 		fn add(self, rhs: Self) -> Self::Output {
-			QQ {inner : (self.inner.0 * rhs.inner.1 + rhs.inner.0 * self.inner.1, self.inner.1 * rhs.inner.1)}
+			let d = gcd(self.inner.1, rhs.inner.1);
+			let (l, r) = (self.inner.1 / d, rhs.inner.1 / d);
+			QQ::from_pair(
+				self.inner.0.checked_mul(r).and_then(|a| a.checked_add(rhs.inner.0.checked_mul(l)?)).expect("QQ: overflow"),
+				self.inner.1.checked_mul(r).expect("QQ: overflow"),
+			)
+		}
+	}
+	impl Sub for QQ {
+		type Output = QQ;
+		fn sub(self, rhs: Self) -> Self::Output {
+			self + (-rhs)
+		}
+	}
+	impl Neg for QQ {
+		type Output = QQ;
+		fn neg(self) -> Self::Output {
+			QQ {inner : (self.inner.0.checked_neg().expect("QQ: overflow"), self.inner.1)}
 		}
 	}
 	impl Mul for QQ {
 		type Output = QQ;
 		fn mul(self, rhs: Self) -> Self::Output {
-			QQ {inner : (self.inner.0 * rhs.inner.0, self.inner.1 * rhs.inner.1)}
+			// cross-cancel first to keep the intermediate values small
+			let g1 = gcd(self.inner.0, rhs.inner.1).max(1);
+			let g2 = gcd(rhs.inner.0, self.inner.1).max(1);
+			QQ::from_pair(
+				(self.inner.0 / g1).checked_mul(rhs.inner.0 / g2).expect("QQ: overflow"),
+				(self.inner.1 / g2).checked_mul(rhs.inner.1 / g1).expect("QQ: overflow"),
+			)
 		}
 	}
 	impl Div for QQ {
 		type Output = QQ;
+		#[allow(clippy::suspicious_arithmetic_impl)]
 		fn div(self, rhs: Self) -> Self::Output {
-			QQ {inner : (self.inner.0 * rhs.inner.1, self.inner.1 * rhs.inner.0)}
+			if rhs.is_zero() {
+				panic!("0 cannot be divisor")
+			}
+			self * QQ::from_pair(rhs.inner.1, rhs.inner.0)
 		}
 	}
+	/// both are in lowest terms, so equal rationals have equal pairs
 	impl PartialEq for QQ {
 		fn eq(&self, other: &Self) -> bool {
-			let tmp = *self / *other;
-			tmp.inner.0 == tmp.inner.1
+			self.inner == other.inner
+		}
+	}
+	#[test] fn qq_eq() {
+		assert_eq!(QQ::from_pair(2, -4), QQ::from_pair(-1, 2));
+		assert_ne!(QQ::from_pair(1, 3), QQ::from_pair(1, 2));
+		// the cross products would overflow
+		let big = i128::MAX / 3;
+		assert_eq!(QQ::from_pair(big, big - 1), QQ::from_pair(big, big - 1));
+		assert_ne!(QQ::from_pair(big, big - 1), QQ::from_pair(big - 1, big - 2));
+	}
+	impl PartialOrd for QQ {
+		fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+			Some(self.cmp(other))
+		}
+	}
+	/// by the continued fractions, comparing the floors and then the reciprocals of the fractional parts,
+	/// which never leaves the range of the entries, unlike the cross products
+	impl Ord for QQ {
+		fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+			use std::cmp::Ordering;
+			// `a / b` against `c / d`, the denominators positive, the order flipped after each reciprocal
+			let ((mut a, mut b), (mut c, mut d)) = (self.inner, other.inner);
+			let mut flipped = false;
+			loop {
+				let (p, q) = (a.div_euclid(b), c.div_euclid(d));
+				let (r, s) = (a.rem_euclid(b), c.rem_euclid(d));
+				let order = match (p.cmp(&q), r, s) {
+					(Ordering::Equal, 0, 0) => Ordering::Equal,
+					(Ordering::Equal, 0, _) => Ordering::Less,
+					(Ordering::Equal, _, 0) => Ordering::Greater,
+					(Ordering::Equal, _, _) => {
+						// `r / b` against `s / d` is the reverse of `b / r` against `d / s`
+						((a, b), (c, d)) = ((b, r), (d, s));
+						flipped = !flipped;
+						continue;
+					}
+					(order, _, _) => order,
+				};
+				return if flipped { order.reverse() } else { order };
+			}
+		}
+	}
+	#[test] fn qq_order() {
+		let q = QQ::from_pair;
+		assert!(q(1, 3) < q(1, 2));
+		assert!(q(-1, 2) < q(-1, 3));
+		assert!(q(-7, 3) < q(2, 1));
+		assert!(q(355, 113) < q(22, 7));
+		assert_eq!(q(6, 4).cmp(&q(3, 2)), std::cmp::Ordering::Equal);
+		for (a, b, c, d) in (-9..=9).flat_map(|a| (1..=6).flat_map(move |b| (-9..=9).flat_map(move |c| (1..=6).map(move |d| (a, b, c, d))))) {
+			assert_eq!(q(a, b).cmp(&q(c, d)), (a * d).cmp(&(c * b)));
 		}
+		// the cross products would overflow
+		let big = i128::MAX / 3;
+		assert!(q(big, big - 1) < q(big - 1, big - 2));
+		assert!(q(-big, big - 1) > q(-(big - 1), big - 2));
+		assert!(q(i128::MAX, 2) > q(i128::MAX - 2, 2));
 	}
 
 	impl AddGroup for QQ {
@@ -243,9 +493,85 @@ pub mod num {
 			QQ {inner: (1, 1)}
 		}
 	}
+	impl IntegralDomain for QQ {
+		fn exact_div(&self, rhs : &Self) -> Option<Self> {
+			if rhs.is_zero() {
+				None
+			} else {
+				Some(*self / *rhs)
+			}
+		}
+	}
 	impl Field for QQ {}
+	impl OrderedField for QQ {}
+	impl From<ZZ> for QQ {
+		fn from(value : ZZ) -> QQ {
+			QQ {inner : (*value, 1)}
+		}
+	}
+
+	/// the prime field `Z / P`, where `P` should be a prime less than `2^32`
+	#[derive(Debug, Clone, Copy, PartialEq, Eq, BasicObject, BasicObjectCopy, Hash)]
+	pub struct Fp<const P : u64> {value : u64}
+	impl<const P : u64> Deref for Fp<P> {
+		type Target = u64;
+		fn deref(&self) -> &Self::Target {
+			&self.value
+		}
+	}
+	impl<const P : u64> Fp<P> {
+		pub fn from(value : i128) -> Fp<P> {
+			Fp { value : value.rem_euclid(P as i128) as u64 }
+		}
+	}
+	impl<const P : u64> Add for Fp<P> { type Output = Fp<P>; fn add(self, other: Fp<P>) -> Fp<P> { Fp { value: (*self + *other) % P } } }
+	impl<const P : u64> Sub for Fp<P> { type Output = Fp<P>; fn sub(self, other: Fp<P>) -> Fp<P> { Fp { value: (*self + P - *other) % P } } }
+	impl<const P : u64> Neg for Fp<P> { type Output = Fp<P>; fn neg(self) -> Fp<P> { Fp { value: (P - *self) % P } } }
+	impl<const P : u64> Mul for Fp<P> { type Output = Fp<P>; fn mul(self, other: Fp<P>) -> Fp<P> { Fp { value: (*self * *other) % P } } }
+	impl<const P : u64> Div for Fp<P> {
+		type Output = Fp<P>;
+		#[allow(clippy::suspicious_arithmetic_impl)]
+		fn div(self, other: Fp<P>) -> Fp<P> {
+			if other.is_zero() {
+				panic!("0 cannot be divisor")
+			}
+			// Fermat's little theorem
+			self * other.pow(P as usize - 2)
+		}
+	}
+	impl<const P : u64> AddGroup for Fp<P> {
+		fn zero() -> Fp<P> { Fp { value : 0 } }
+	}
+	impl<const P : u64> Ring for Fp<P> {
+		fn one() -> Fp<P> { Fp { value : 1 % P } }
+		fn convolution(lhs : &[Fp<P>], rhs : &[Fp<P>]) -> Vec<Fp<P>> {
+			poly::fast_mul::fp_convolution(lhs, rhs)
+		}
+	}
+	impl<const P : u64> IntegralDomain for Fp<P> {
+		fn exact_div(&self, rhs : &Self) -> Option<Self> {
+			if rhs.is_zero() { None } else { Some(*self / *rhs) }
+		}
+	}
+	impl<const P : u64> Field for Fp<P> {}
+	#[test] fn prime_field() {
+		type F = Fp<7>;
+		assert_eq!(F::from(-1), F::from(6));
+		assert_eq!(F::from(3) * F::from(5), F::one());
+		assert_eq!(F::one() / F::from(3), F::from(5));
+		assert_eq!(F::from(3).pow(6), F::one());
+	}
 
-	// impl Numeric for QQ {fn numeric(&self) -> f64 { (self.inner.1 / self.inner.0).into() }}
+	impl Numeric for QQ {
+		fn numeric(&self) -> f64 { numeric::round_quotient(self.inner.0, self.inner.1) }
+		fn enclosure(&self) -> Interval {
+			// `x` is exact iff `x * d - n` vanishes, which fma computes with a single rounding
+			let x = self.numeric();
+			let exact = self.inner.1 < (1 << 53) && self.inner.0.unsigned_abs() < (1 << 53)
+				&& x.mul_add(self.inner.1 as f64, -(self.inner.0 as f64)) == 0.0;
+			Interval::from_rounded(x, exact)
+		}
+	}
 
 	#[test] fn field() {
 		let a = ZZ {value : 2};
@@ -255,31 +581,29 @@ pub mod num {
 		assert_eq!(q, QQ { inner: (1, 2) })
 	}
 
-	#[derive(Debug, Clone, Copy, PartialEq, Eq, BasicObject)]
-	struct V {inner : (QQ, QQ)}
+	#[derive(Debug, Clone, Copy, PartialEq, Eq, BasicObject, AddGroup, Module)]
+	struct V(QQ, QQ);
 	impl BasicObjectCopy for V {}
 
-	impl ScaMul<QQ> for V {
-		fn sca_mul(self, sca: QQ) -> Self {
-			V {inner : (sca * self.inner.0, sca *self.inner.1)}
-		}
+	#[cfg(test)]
+	#[derive(Debug, Clone, PartialEq, Eq, BasicObject, AddGroup, Ring, Module)]
+	#[module(QQ)]
+	struct Pair<T> {
+		left : T,
+		right : T,
 	}
 
-	impl Add for V {
-		type Output = V;
-		fn add(self, rhs: Self) -> Self::Output {
-			V {inner : (self.inner.0 + rhs.inner.0, self.inner.1 + rhs.inner.1)}
-		}
-	}
-	impl AddGroup for V {
-		fn zero() -> V { V { inner: (QQ::zero(), QQ::zero()) } }
-	}
-	impl Module<QQ> for V {}
-
 	#[test] fn vec_qq2() {
-		let v = V { inner : (QQ {inner : (1, 2)}, QQ { inner : (7, 1)})};
+		let v = V(QQ {inner : (1, 2)}, QQ { inner : (7, 1)});
 
-		assert_eq!(v.sca_mul(QQ { inner: (5, 1) }), V { inner : (QQ {inner : (5, 2)}, QQ { inner : (35, 1)})})
+		assert_eq!(v.sca_mul(QQ { inner: (5, 1) }), V(QQ {inner : (5, 2)}, QQ { inner : (35, 1)}));
+		assert_eq!(v - v, V::zero());
+		assert_eq!(-v + v.times(3), v.sca_mul(QQ::from_pair(2, 1)));
+
+		let p = Pair { left : QQ::from_pair(2, 1), right : QQ::from_pair(-3, 1) };
+		assert_eq!(p.clone() * p.clone(), p.clone().sca_mul(p.left) + Pair { left : QQ::zero(), right : QQ::from_pair(15, 1) });
+		assert_eq!(p.pow(3), Pair { left : QQ::from_pair(8, 1), right : QQ::from_pair(-27, 1) });
+		assert!(Pair::<QQ>::one().is_one());
 	}
 
 	impl ScaMul<QQ> for QQ {
@@ -350,7 +674,7 @@ pub mod num {
 				var
 			}
 		}
-		pub fn from_vec_int(var : Var<'a>, mut v : Vec<u128>) -> QQPolynomial<'a> {
+		pub fn from_vec_int(var : Var<'a>, mut v : Vec<i128>) -> QQPolynomial<'a> {
 			while let Some(&0) = v.last() {
 				v.pop();
 			}
@@ -359,6 +683,10 @@ pub mod num {
 				var
 			}
 		}
+		/// forget the variable, keep the coefficients
+		pub fn to_uni_polynomial(&self) -> UniPolynomial<QQ> {
+			UniPolynomial::from(self.inner.clone())
+		}
 		// TODO: parse 字符串来得到 QQPolynomial
 
 		/// to check whether the polynomial is already simplied
@@ -442,7 +770,7 @@ pub mod num {
 
 		// eval
 		let f = QQPolynomial::from_vec_int(var, vec![0, 1, 2, 3, 4]); // 
-		let fp = |x : u128| {0 + 1 * x + 2 * x.pow(2) + 3 * x.pow(3) + 4 * x.pow(4)};
+		let fp = |x : i128| {0 + 1 * x + 2 * x.pow(2) + 3 * x.pow(3) + 4 * x.pow(4)};
 		assert_eq!(f.eval(HashMap::from([(var , QQ::from_pair(5, 1))])), QQ::from_pair(fp(5), 1));
 
 		// try_into_monomial
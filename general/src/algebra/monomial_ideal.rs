@@ -0,0 +1,307 @@
+//! monomial ideals of `k[x_1, ..., x_n]`, where the variables are those of a `VarTable`
+
+use std::collections::HashMap;
+
+use alias::BasicObject;
+
+use crate::algebra::{AddGroup, Ring, IntegralDomain, Var, VarTable, MonomialFormat, MultiPolynomial, UniPolynomial};
+use crate::algebra::num::ZZ;
+
+impl<'a> MonomialFormat<'a> {
+	/// the monomial `1`
+	pub fn one() -> MonomialFormat<'a> {
+		MonomialFormat(HashMap::new())
+	}
+	/// forget the variables with exponent zero, so that equal monomials are equal formats
+	fn normalized(mut self) -> MonomialFormat<'a> {
+		self.0.retain(|_, n| *n > 0);
+		self
+	}
+	pub fn divides(&self, other : &MonomialFormat<'a>) -> bool {
+		self.iter().all(|(v, n)| *n == 0 || other.get(v).is_some_and(|m| m >= n))
+	}
+	pub fn mul(&self, other : &MonomialFormat<'a>) -> MonomialFormat<'a> {
+		let mut m = self.0.clone();
+		for (v, n) in other.iter() {
+			*m.entry(*v).or_insert(0) += n;
+		}
+		MonomialFormat(m).normalized()
+	}
+	pub fn lcm(&self, other : &MonomialFormat<'a>) -> MonomialFormat<'a> {
+		let mut m = self.0.clone();
+		for (v, n) in other.iter() {
+			let e = m.entry(*v).or_insert(0);
+			*e = (*e).max(*n);
+		}
+		MonomialFormat(m).normalized()
+	}
+	/// `self / gcd(self, other)`
+	pub fn colon(&self, other : &MonomialFormat<'a>) -> MonomialFormat<'a> {
+		MonomialFormat(self.iter().map(|(v, n)| (*v, n - (*n).min(other.get(v).copied().unwrap_or(0)))).collect()).normalized()
+	}
+	pub fn total_degree(&self) -> usize {
+		self.values().sum()
+	}
+	/// the exponents indexed by the variables of the table
+	fn exponent(&self, vt : &VarTable) -> Vec<usize> {
+		let mut e = vec![0; vt.len()];
+		for (v, n) in self.iter() {
+			e[v.index] = *n;
+		}
+		e
+	}
+}
+
+/// a monomial ideal, kept by its minimal generators
+///
+/// the zero ideal has no generators and the unit ideal is generated by `1`
+#[derive(Debug, Clone, PartialEq, Eq, BasicObject)]
+pub struct MonomialIdeal<'a> {
+	generators : Vec<MonomialFormat<'a>>,
+	source : &'a VarTable,
+}
+
+/// `numerator / ((1 - x_1) ... (1 - x_n))`, where `x_1, ..., x_n` are all the variables of the table
+#[derive(Debug, Clone, PartialEq, Eq, BasicObject)]
+pub struct HilbertSeries {
+	pub numerator : MultiPolynomial<ZZ>,
+	pub variables : usize,
+}
+
+impl<'a> MonomialIdeal<'a> {
+	pub fn new(vt : &'a VarTable, generators : Vec<MonomialFormat<'a>>) -> MonomialIdeal<'a> {
+		let mut candidates : Vec<MonomialFormat<'a>> = generators.into_iter().map(MonomialFormat::normalized).collect();
+		candidates.sort_by_key(MonomialFormat::total_degree);
+		let mut minimal : Vec<MonomialFormat<'a>> = Vec::new();
+		for m in candidates {
+			if !minimal.iter().any(|g| g.divides(&m)) {
+				minimal.push(m);
+			}
+		}
+		// a canonical order, so that equal ideals are equal
+		minimal.sort_by_key(|m| m.exponent(vt));
+		MonomialIdeal { generators : minimal, source : vt }
+	}
+
+	pub fn zero(vt : &'a VarTable) -> MonomialIdeal<'a> {
+		MonomialIdeal::new(vt, Vec::new())
+	}
+
+	pub fn unit(vt : &'a VarTable) -> MonomialIdeal<'a> {
+		MonomialIdeal::new(vt, vec![MonomialFormat::one()])
+	}
+
+	/// the Stanley–Reisner ideal of the simplicial complex on the variables without the faces containing a non-face,
+	/// generated by the squarefree monomials of the non-faces
+	pub fn stanley_reisner(vt : &'a VarTable, non_faces : &[Vec<Var<'a>>]) -> MonomialIdeal<'a> {
+		MonomialIdeal::new(vt, non_faces.iter().map(|f| MonomialFormat(f.iter().map(|v| (*v, 1)).collect())).collect())
+	}
+
+	/// the minimal generators
+	pub fn generators(&self) -> &[MonomialFormat<'a>] {
+		&self.generators
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.generators.is_empty()
+	}
+
+	pub fn contains(&self, m : &MonomialFormat<'a>) -> bool {
+		self.generators.iter().any(|g| g.divides(m))
+	}
+
+	/// a polynomial is in a monomial ideal if and only if every of its terms is
+	pub fn contains_polynomial<R : Ring>(&self, f : &MultiPolynomial<R>) -> bool {
+		f.keys().all(|e| self.generators.iter().any(|g| g.iter().all(|(v, n)| e.get(v.index).is_some_and(|m| m >= n))))
+	}
+
+	pub fn sum(&self, other : &MonomialIdeal<'a>) -> MonomialIdeal<'a> {
+		MonomialIdeal::new(self.source, self.generators.iter().chain(other.generators.iter()).cloned().collect())
+	}
+
+	pub fn product(&self, other : &MonomialIdeal<'a>) -> MonomialIdeal<'a> {
+		MonomialIdeal::new(self.source, self.generators.iter().flat_map(|g| other.generators.iter().map(|h| g.mul(h))).collect())
+	}
+
+	/// generated by the pairwise least common multiples
+	pub fn intersection(&self, other : &MonomialIdeal<'a>) -> MonomialIdeal<'a> {
+		MonomialIdeal::new(self.source, self.generators.iter().flat_map(|g| other.generators.iter().map(|h| g.lcm(h))).collect())
+	}
+
+	/// `I : m = {f | f m in I}`, generated by `g / gcd(g, m)`
+	pub fn colon_monomial(&self, m : &MonomialFormat<'a>) -> MonomialIdeal<'a> {
+		MonomialIdeal::new(self.source, self.generators.iter().map(|g| g.colon(m)).collect())
+	}
+
+	/// `I : J`, the intersection of `I : h` over the generators `h` of `J`
+	pub fn colon(&self, other : &MonomialIdeal<'a>) -> MonomialIdeal<'a> {
+		other.generators.iter().fold(MonomialIdeal::unit(self.source), |acc, h| acc.intersection(&self.colon_monomial(h)))
+	}
+
+	/// the multigraded Hilbert series of `k[x_1, ..., x_n] / I`
+	///
+	/// the numerator is the K-polynomial, by `K(S / (I + (m))) = K(S / I) - x^m K(S / (I : m))`
+	pub fn hilbert_series(&self) -> HilbertSeries {
+		HilbertSeries { numerator : self.k_polynomial(), variables : self.source.len() }
+	}
+
+	/// the generators coprime to all the others split off as the factors `1 - x^m`,
+	/// and the rest by a pivot `p = x^k` not in `I`, `K(S / I) = K(S / (I + (p))) + x^p K(S / (I : p))`,
+	/// where `x` is a variable in the most generators and `k` its least positive exponent among them, as in Bigatti's algorithm
+	fn k_polynomial(&self) -> MultiPolynomial<ZZ> {
+		if self.generators.iter().any(|g| g.total_degree() == 0) {
+			return MultiPolynomial::zero();
+		}
+		let shares_variable = |g : &MonomialFormat<'a>, h : &MonomialFormat<'a>| g.keys().any(|v| h.contains_key(v));
+		let (coprime, rest) : (Vec<_>, Vec<_>) = self.generators.iter().enumerate()
+			.partition(|(i, g)| self.generators.iter().enumerate().all(|(j, h)| *i == j || !shares_variable(g, h)));
+		let factor = coprime.into_iter().fold(MultiPolynomial::one(), |acc, (_, m)| acc * (MultiPolynomial::one() - MultiPolynomial::from_format(m, ZZ::one())));
+		if rest.is_empty() {
+			return factor;
+		}
+		let mut occurrences : HashMap<Var<'a>, (usize, usize)> = HashMap::new();
+		for (_, g) in rest.iter() {
+			for (v, n) in g.iter() {
+				let entry = occurrences.entry(*v).or_insert((0, *n));
+				*entry = (entry.0 + 1, entry.1.min(*n));
+			}
+		}
+		// a variable shared by two generators, whose least exponent makes a pivot which is not in the ideal
+		let (x, (_, k)) = occurrences.into_iter().max_by_key(|(v, (count, _))| (*count, std::cmp::Reverse(v.index))).unwrap();
+		let pivot = MonomialFormat(HashMap::from([(x, k)]));
+		let rest = MonomialIdeal::new(self.source, rest.into_iter().map(|(_, g)| g.clone()).collect());
+		let with_pivot = MonomialIdeal::new(self.source, rest.generators.iter().cloned().chain([pivot.clone()]).collect());
+		factor * (with_pivot.k_polynomial() + MultiPolynomial::from_format(&pivot, ZZ::one()) * rest.colon_monomial(&pivot).k_polynomial())
+	}
+}
+
+impl HilbertSeries {
+	/// the dimension of the graded piece of multidegree `e`,
+	/// as `1 / ((1 - x_1) ... (1 - x_n))` is the sum of all monomials
+	pub fn coefficient(&self, e : &[usize]) -> ZZ {
+		self.numerator.iter()
+			.filter(|(a, _)| a.iter().enumerate().all(|(i, n)| e.get(i).is_some_and(|m| m >= n)))
+			.fold(ZZ::zero(), |acc, (_, c)| acc + *c)
+	}
+
+	/// `(h, d)` with `h(t) / (1 - t)^d` the series in the standard grading and `h(1) != 0`,
+	/// so `d` is the Krull dimension and `h(1)` the degree of the quotient
+	pub fn coarsen(&self) -> (UniPolynomial<ZZ>, usize) {
+		let mut h = UniPolynomial::from(vec![]);
+		for (e, c) in self.numerator.iter() {
+			h = h + UniPolynomial::monomial(*c, e.iter().sum());
+		}
+		let one_minus_t = UniPolynomial::from(vec![ZZ::one(), -ZZ::one()]);
+		let mut d = self.variables;
+		while d > 0 && !h.is_zero() {
+			match h.exact_div(&one_minus_t) {
+				Some(q) => {
+					h = q;
+					d -= 1;
+				},
+				None => break,
+			}
+		}
+		(h, d)
+	}
+}
+
+#[cfg(test)]
+fn table(n : usize) -> VarTable {
+	let mut vt = VarTable::new();
+	for i in 0..n {
+		vt.push(format!("x{}", i));
+	}
+	vt
+}
+
+#[test] fn ideal_operations() {
+	let vt = table(2);
+	let (x, y) = (Var::from_index(0, &vt), Var::from_index(1, &vt));
+	let m = |a : usize, b : usize| MonomialFormat::from(HashMap::from([(x, a), (y, b)]));
+	let ideal = |gens| MonomialIdeal::new(&vt, gens);
+
+	let i = ideal(vec![m(2, 0), m(1, 1), m(3, 1), m(2, 0)]);
+	assert_eq!(i.generators().len(), 2);
+	assert!(i.contains(&m(5, 0)) && i.contains(&m(1, 4)) && !i.contains(&m(0, 7)));
+	let j = ideal(vec![m(0, 2)]);
+
+	assert_eq!(i.sum(&j), ideal(vec![m(2, 0), m(1, 1), m(0, 2)]));
+	assert_eq!(i.product(&j), ideal(vec![m(2, 2), m(1, 3)]));
+	assert_eq!(i.intersection(&j), ideal(vec![m(2, 2), m(1, 2)]));
+	assert_eq!(i.colon_monomial(&m(1, 0)), ideal(vec![m(1, 0), m(0, 1)]));
+	assert_eq!(i.colon(&ideal(vec![m(1, 0), m(0, 1)])), ideal(vec![m(1, 0)]));
+	assert_eq!(i.colon(&MonomialIdeal::zero(&vt)), MonomialIdeal::unit(&vt));
+	assert!(MonomialIdeal::unit(&vt).contains(&m(0, 0)));
+
+	let f = MultiPolynomial::<ZZ>::from_format(&m(3, 0), ZZ::from(2)) - MultiPolynomial::from_format(&m(1, 2), ZZ::one());
+	assert!(i.contains_polynomial(&f));
+	assert!(!i.contains_polynomial(&(f + MultiPolynomial::from_var(y))));
+}
+
+#[test] fn hilbert_series() {
+	let vt = table(3);
+	let v : Vec<Var> = (0..3).map(|i| Var::from_index(i, &vt)).collect();
+
+	// the boundary of a triangle, so the quotient is k[x, y, z] / (xyz)
+	let boundary = MonomialIdeal::stanley_reisner(&vt, std::slice::from_ref(&v));
+	let series = boundary.hilbert_series();
+	assert_eq!(series.numerator, MultiPolynomial::one() - MultiPolynomial::from_terms([(vec![1, 1, 1], ZZ::one())]));
+	assert_eq!(series.coarsen(), (UniPolynomial::from(vec![ZZ::one(); 3]), 2));
+	assert_eq!(series.coefficient(&[2, 0, 5]), ZZ::one());
+	assert_eq!(series.coefficient(&[2, 1, 5]), ZZ::zero());
+
+	// three isolated points: all the edges are non-faces, a non-minimal non-face changes nothing
+	let points = MonomialIdeal::stanley_reisner(&vt, &[
+		vec![v[0], v[1]], vec![v[0], v[2]], vec![v[1], v[2]], v.clone(),
+	]);
+	assert_eq!(points.generators().len(), 3);
+	// (1 + 2t) / (1 - t), that is three points
+	assert_eq!(points.hilbert_series().coarsen(), (UniPolynomial::from(vec![ZZ::one(), ZZ::from(2)]), 1));
+
+	// a non-squarefree ideal: k[x, y] / (x^2, y^3) has dimension 6
+	let vt = table(2);
+	let (x, y) = (Var::from_index(0, &vt), Var::from_index(1, &vt));
+	let artinian = MonomialIdeal::new(&vt, vec![MonomialFormat::from(HashMap::from([(x, 2)])), MonomialFormat::from(HashMap::from([(y, 3)]))]);
+	let (h, d) = artinian.hilbert_series().coarsen();
+	assert_eq!((h, d), (UniPolynomial::from(vec![ZZ::one(), ZZ::from(2), ZZ::from(2), ZZ::one()]), 0));
+	// the zero ideal
+	assert_eq!(MonomialIdeal::zero(&vt).hilbert_series().coarsen(), (UniPolynomial::from(vec![ZZ::one()]), 2));
+}
+
+#[test] fn k_polynomial_pivots() {
+	// the coefficients of the series are 1 exactly on the monomials outside the ideal
+	let vt = table(3);
+	let v : Vec<Var> = (0..3).map(|i| Var::from_index(i, &vt)).collect();
+	let m = |e : [usize; 3]| MonomialFormat::from(v.iter().cloned().zip(e).collect());
+	for gens in [
+		vec![[2, 1, 0], [0, 3, 1], [1, 0, 2], [1, 1, 1]],
+		vec![[3, 0, 0], [0, 3, 0], [0, 0, 3], [1, 1, 1], [2, 2, 0]],
+		vec![[1, 2, 0], [2, 1, 0], [0, 1, 2], [0, 2, 1], [4, 0, 0]],
+	] {
+		let ideal = MonomialIdeal::new(&vt, gens.into_iter().map(m).collect());
+		let series = ideal.hilbert_series();
+		for a in 0..5 {
+			for b in 0..5 {
+				for c in 0..5 {
+					let inside = ideal.contains(&m([a, b, c]));
+					assert_eq!(series.coefficient(&[a, b, c]), if inside { ZZ::zero() } else { ZZ::one() });
+				}
+			}
+		}
+	}
+
+	// the 10 coprime generators `x_{2i} x_{2i + 1}` of 20 variables split off at once as `Π (1 - x_{2i} x_{2i + 1})`
+	let vt = table(20);
+	let v : Vec<Var> = (0..20).map(|i| Var::from_index(i, &vt)).collect();
+	let matching = MonomialIdeal::stanley_reisner(&vt, &(0..10).map(|i| vec![v[2 * i], v[2 * i + 1]]).collect::<Vec<_>>());
+	let series = matching.hilbert_series();
+	assert_eq!(series.numerator.len(), 1 << 10);
+	assert_eq!(series.coefficient(&[1; 20]), ZZ::zero());
+	assert_eq!(series.coefficient(&(0..20).map(|i| (i + 1) % 2).collect::<Vec<_>>()), ZZ::one());
+	assert_eq!(series.coarsen().1, 10);
+	// the 19 generators `x_i x_{i + 1}` of a path overlap, and need pivots,
+	// the dimension of the quotient is the size of the largest independent sets of the path, 10
+	let path = MonomialIdeal::stanley_reisner(&vt, &(0..19).map(|i| vec![v[i], v[i + 1]]).collect::<Vec<_>>());
+	assert_eq!(path.hilbert_series().coarsen().1, 10);
+}
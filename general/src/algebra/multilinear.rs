@@ -0,0 +1,374 @@
+//! the exterior algebra `Λ V` and the symmetric algebra `Sym V` of a free module `V = R^n`,
+//! graded by the powers `Λ^k V` and `Sym^k V`
+//!
+//! the basis vectors of `V` are `e_0, e_1, ...`, so elements do not need to know `n`,
+//! and a linear map `f : R^n -> R^m` is given by the images `f(e_0), ..., f(e_{n-1})` as vectors of `R^m`
+
+use std::collections::BTreeMap;
+use std::ops::{Add, Deref, Mul, Neg, Sub};
+
+use alias::BasicObject;
+
+use crate::algebra::{AddGroup, Ring, ScaMul, Module, Algebra};
+
+/// the `k`-subsets of `0..n`, in lexicographic order
+pub fn subsets(n : usize, k : usize) -> Vec<Vec<usize>> {
+	fn extend(start : usize, n : usize, k : usize, current : &mut Vec<usize>, result : &mut Vec<Vec<usize>>) {
+		if current.len() == k {
+			result.push(current.clone());
+			return;
+		}
+		for i in start..n {
+			current.push(i);
+			extend(i + 1, n, k, current, result);
+			current.pop();
+		}
+	}
+	let mut result = Vec::new();
+	extend(0, n, k, &mut Vec::new(), &mut result);
+	result
+}
+
+/// the `k`-multisets of `0..n` as weakly increasing sequences, in lexicographic order
+pub fn multisets(n : usize, k : usize) -> Vec<Vec<usize>> {
+	fn extend(start : usize, n : usize, k : usize, current : &mut Vec<usize>, result : &mut Vec<Vec<usize>>) {
+		if current.len() == k {
+			result.push(current.clone());
+			return;
+		}
+		for i in start..n {
+			current.push(i);
+			extend(i, n, k, current, result);
+			current.pop();
+		}
+	}
+	let mut result = Vec::new();
+	extend(0, n, k, &mut Vec::new(), &mut result);
+	result
+}
+
+fn collect<R : Ring>(terms : impl IntoIterator<Item = (Vec<usize>, R)>) -> BTreeMap<Vec<usize>, R> {
+	let mut m : BTreeMap<Vec<usize>, R> = BTreeMap::new();
+	for (e, c) in terms {
+		let c = match m.remove(&e) {
+			Some(old) => old + c,
+			None => c,
+		};
+		if !c.is_zero() {
+			m.insert(e, c);
+		}
+	}
+	m
+}
+
+/// an element of `Λ V`, as coefficients of the basis `e_S = e_{s_1} ∧ ... ∧ e_{s_k}` with `s_1 < ... < s_k`
+#[derive(Debug, Clone, PartialEq, Eq, BasicObject)]
+pub struct ExteriorAlgebra<R : Ring>(BTreeMap<Vec<usize>, R>);
+impl<R : Ring> Deref for ExteriorAlgebra<R> {
+	type Target = BTreeMap<Vec<usize>, R>;
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+/// `e_S ∧ e_T` as `e_U` with a sign, `None` if `S` and `T` meet
+fn wedge_basis(s : &[usize], t : &[usize]) -> Option<(Vec<usize>, bool)> {
+	let mut u = Vec::with_capacity(s.len() + t.len());
+	let (mut i, mut j, mut odd) = (0, 0, false);
+	while i < s.len() || j < t.len() {
+		if j == t.len() || (i < s.len() && s[i] < t[j]) {
+			u.push(s[i]);
+			i += 1;
+		} else if i == s.len() || t[j] < s[i] {
+			// t[j] moves past the s.len() - i remaining elements of s
+			odd ^= (s.len() - i) % 2 == 1;
+			u.push(t[j]);
+			j += 1;
+		} else {
+			return None;
+		}
+	}
+	Some((u, odd))
+}
+
+impl<R : Ring> ExteriorAlgebra<R> {
+	/// `c e_{s_1} ∧ ... ∧ e_{s_k}` for indices in any order, zero if one is repeated
+	pub fn from_indices(indices : &[usize], c : R) -> ExteriorAlgebra<R> {
+		indices.iter().fold(ExteriorAlgebra::constant(c), |acc, i| acc * ExteriorAlgebra::basis_vector(*i))
+	}
+
+	pub fn constant(c : R) -> ExteriorAlgebra<R> {
+		ExteriorAlgebra(collect([(Vec::new(), c)]))
+	}
+
+	/// `e_i` in `Λ^1 V = V`
+	pub fn basis_vector(i : usize) -> ExteriorAlgebra<R> {
+		ExteriorAlgebra(collect([(vec![i], R::one())]))
+	}
+
+	/// `v_0 e_0 + v_1 e_1 + ...`
+	pub fn from_vector(v : &[R]) -> ExteriorAlgebra<R> {
+		ExteriorAlgebra(collect(v.iter().enumerate().map(|(i, c)| (vec![i], c.clone()))))
+	}
+
+	/// the basis `e_S` of `Λ^k R^n`, indexed by the `k`-subsets of `0..n` in lexicographic order
+	pub fn basis(n : usize, k : usize) -> Vec<ExteriorAlgebra<R>> {
+		subsets(n, k).into_iter().map(|s| ExteriorAlgebra(collect([(s, R::one())]))).collect()
+	}
+
+	/// the coefficient of `e_S`, where `S` is increasing
+	pub fn coef(&self, s : &[usize]) -> R {
+		self.0.get(s).cloned().unwrap_or_else(R::zero)
+	}
+
+	/// the component in `Λ^k V`
+	pub fn homogeneous(&self, k : usize) -> ExteriorAlgebra<R> {
+		ExteriorAlgebra(self.0.iter().filter(|(s, _)| s.len() == k).map(|(s, c)| (s.clone(), c.clone())).collect())
+	}
+
+	/// the coordinates of the component in `Λ^k R^n`, in the order of `basis(n, k)`
+	pub fn coordinates(&self, n : usize, k : usize) -> Vec<R> {
+		subsets(n, k).iter().map(|s| self.coef(s)).collect()
+	}
+
+	pub fn wedge(&self, rhs : &ExteriorAlgebra<R>) -> ExteriorAlgebra<R> {
+		self.clone() * rhs.clone()
+	}
+
+	/// `Λ(f)`, the algebra map with `e_{s_1} ∧ ... ∧ e_{s_k} -> f(e_{s_1}) ∧ ... ∧ f(e_{s_k})`
+	pub fn map(&self, images : &[Vec<R>]) -> ExteriorAlgebra<R> {
+		let images : Vec<ExteriorAlgebra<R>> = images.iter().map(|v| ExteriorAlgebra::from_vector(v)).collect();
+		self.0.iter().fold(ExteriorAlgebra::zero(), |acc, (s, c)| {
+			acc + s.iter().fold(ExteriorAlgebra::constant(c.clone()), |w, i| w * images[*i].clone())
+		})
+	}
+}
+
+/// the matrix of `Λ^k(f) : Λ^k R^n -> Λ^k R^m` in the bases `basis(n, k)` and `basis(m, k)`,
+/// in the same convention as `f`: the images of the basis vectors, whose entries are the `k x k` minors of `f`
+pub fn exterior_power<R : Ring>(images : &[Vec<R>], m : usize, k : usize) -> Vec<Vec<R>> {
+	ExteriorAlgebra::basis(images.len(), k).iter().map(|e| e.map(images).coordinates(m, k)).collect()
+}
+
+impl<R : Ring> Add for ExteriorAlgebra<R> {
+	type Output = ExteriorAlgebra<R>;
+	fn add(self, rhs : Self) -> Self::Output {
+		ExteriorAlgebra(collect(self.0.into_iter().chain(rhs.0)))
+	}
+}
+impl<R : Ring> Neg for ExteriorAlgebra<R> {
+	type Output = ExteriorAlgebra<R>;
+	fn neg(self) -> Self::Output {
+		ExteriorAlgebra(self.0.into_iter().map(|(s, c)| (s, -c)).collect())
+	}
+}
+impl<R : Ring> Sub for ExteriorAlgebra<R> {
+	type Output = ExteriorAlgebra<R>;
+	fn sub(self, rhs : Self) -> Self::Output {
+		self + (-rhs)
+	}
+}
+/// the wedge product, which is graded commutative: `a ∧ b = (-1)^{jk} b ∧ a` for `a` in `Λ^j` and `b` in `Λ^k`
+impl<R : Ring> Mul for ExteriorAlgebra<R> {
+	type Output = ExteriorAlgebra<R>;
+	fn mul(self, rhs : Self) -> Self::Output {
+		ExteriorAlgebra(collect(self.0.iter().flat_map(|(s, c)| {
+			rhs.0.iter().filter_map(move |(t, d)| {
+				let (u, odd) = wedge_basis(s, t)?;
+				let c = c.clone() * d.clone();
+				Some((u, if odd { -c } else { c }))
+			})
+		})))
+	}
+}
+impl<R : Ring> AddGroup for ExteriorAlgebra<R> {
+	fn zero() -> Self {
+		ExteriorAlgebra(BTreeMap::new())
+	}
+}
+impl<R : Ring> Ring for ExteriorAlgebra<R> {
+	fn one() -> Self {
+		ExteriorAlgebra::constant(R::one())
+	}
+}
+impl<R : Ring> ScaMul<R> for ExteriorAlgebra<R> {
+	fn sca_mul(self, sca : R) -> Self {
+		ExteriorAlgebra(collect(self.0.into_iter().map(|(s, c)| (s, sca.clone() * c))))
+	}
+}
+impl<R : Ring> Module<R> for ExteriorAlgebra<R> {}
+impl<R : Ring> Algebra<R> for ExteriorAlgebra<R> {}
+
+/// an element of `Sym V`, as coefficients of the basis `e_{s_1} ... e_{s_k}` with `s_1 <= ... <= s_k`
+#[derive(Debug, Clone, PartialEq, Eq, BasicObject)]
+pub struct SymmetricAlgebra<R : Ring>(BTreeMap<Vec<usize>, R>);
+impl<R : Ring> Deref for SymmetricAlgebra<R> {
+	type Target = BTreeMap<Vec<usize>, R>;
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<R : Ring> SymmetricAlgebra<R> {
+	/// `c e_{s_1} ... e_{s_k}` for indices in any order
+	pub fn from_indices(indices : &[usize], c : R) -> SymmetricAlgebra<R> {
+		let mut s = indices.to_vec();
+		s.sort();
+		SymmetricAlgebra(collect([(s, c)]))
+	}
+
+	pub fn constant(c : R) -> SymmetricAlgebra<R> {
+		SymmetricAlgebra(collect([(Vec::new(), c)]))
+	}
+
+	/// `e_i` in `Sym^1 V = V`
+	pub fn basis_vector(i : usize) -> SymmetricAlgebra<R> {
+		SymmetricAlgebra(collect([(vec![i], R::one())]))
+	}
+
+	/// `v_0 e_0 + v_1 e_1 + ...`
+	pub fn from_vector(v : &[R]) -> SymmetricAlgebra<R> {
+		SymmetricAlgebra(collect(v.iter().enumerate().map(|(i, c)| (vec![i], c.clone()))))
+	}
+
+	/// the basis of `Sym^k R^n`, indexed by the `k`-multisets of `0..n` in lexicographic order
+	pub fn basis(n : usize, k : usize) -> Vec<SymmetricAlgebra<R>> {
+		multisets(n, k).into_iter().map(|s| SymmetricAlgebra(collect([(s, R::one())]))).collect()
+	}
+
+	/// the coefficient of `e_{s_1} ... e_{s_k}`, where `s` is weakly increasing
+	pub fn coef(&self, s : &[usize]) -> R {
+		self.0.get(s).cloned().unwrap_or_else(R::zero)
+	}
+
+	/// the component in `Sym^k V`
+	pub fn homogeneous(&self, k : usize) -> SymmetricAlgebra<R> {
+		SymmetricAlgebra(self.0.iter().filter(|(s, _)| s.len() == k).map(|(s, c)| (s.clone(), c.clone())).collect())
+	}
+
+	/// the coordinates of the component in `Sym^k R^n`, in the order of `basis(n, k)`
+	pub fn coordinates(&self, n : usize, k : usize) -> Vec<R> {
+		multisets(n, k).iter().map(|s| self.coef(s)).collect()
+	}
+
+	/// `Sym(f)`, the algebra map with `e_{s_1} ... e_{s_k} -> f(e_{s_1}) ... f(e_{s_k})`
+	pub fn map(&self, images : &[Vec<R>]) -> SymmetricAlgebra<R> {
+		let images : Vec<SymmetricAlgebra<R>> = images.iter().map(|v| SymmetricAlgebra::from_vector(v)).collect();
+		self.0.iter().fold(SymmetricAlgebra::zero(), |acc, (s, c)| {
+			acc + s.iter().fold(SymmetricAlgebra::constant(c.clone()), |w, i| w * images[*i].clone())
+		})
+	}
+}
+
+/// the matrix of `Sym^k(f) : Sym^k R^n -> Sym^k R^m` in the bases `basis(n, k)` and `basis(m, k)`,
+/// in the same convention as `f`
+pub fn symmetric_power<R : Ring>(images : &[Vec<R>], m : usize, k : usize) -> Vec<Vec<R>> {
+	SymmetricAlgebra::basis(images.len(), k).iter().map(|e| e.map(images).coordinates(m, k)).collect()
+}
+
+impl<R : Ring> Add for SymmetricAlgebra<R> {
+	type Output = SymmetricAlgebra<R>;
+	fn add(self, rhs : Self) -> Self::Output {
+		SymmetricAlgebra(collect(self.0.into_iter().chain(rhs.0)))
+	}
+}
+impl<R : Ring> Neg for SymmetricAlgebra<R> {
+	type Output = SymmetricAlgebra<R>;
+	fn neg(self) -> Self::Output {
+		SymmetricAlgebra(self.0.into_iter().map(|(s, c)| (s, -c)).collect())
+	}
+}
+impl<R : Ring> Sub for SymmetricAlgebra<R> {
+	type Output = SymmetricAlgebra<R>;
+	fn sub(self, rhs : Self) -> Self::Output {
+		self + (-rhs)
+	}
+}
+impl<R : Ring> Mul for SymmetricAlgebra<R> {
+	type Output = SymmetricAlgebra<R>;
+	fn mul(self, rhs : Self) -> Self::Output {
+		SymmetricAlgebra(collect(self.0.iter().flat_map(|(s, c)| {
+			rhs.0.iter().map(move |(t, d)| {
+				let mut u = [s.as_slice(), t.as_slice()].concat();
+				u.sort();
+				(u, c.clone() * d.clone())
+			})
+		})))
+	}
+}
+impl<R : Ring> AddGroup for SymmetricAlgebra<R> {
+	fn zero() -> Self {
+		SymmetricAlgebra(BTreeMap::new())
+	}
+}
+impl<R : Ring> Ring for SymmetricAlgebra<R> {
+	fn one() -> Self {
+		SymmetricAlgebra::constant(R::one())
+	}
+}
+impl<R : Ring> ScaMul<R> for SymmetricAlgebra<R> {
+	fn sca_mul(self, sca : R) -> Self {
+		SymmetricAlgebra(collect(self.0.into_iter().map(|(s, c)| (s, sca.clone() * c))))
+	}
+}
+impl<R : Ring> Module<R> for SymmetricAlgebra<R> {}
+impl<R : Ring> Algebra<R> for SymmetricAlgebra<R> {}
+
+/// `g ∘ f` in the convention of images of basis vectors
+#[cfg(test)]
+fn compose<R : Ring>(g : &[Vec<R>], f : &[Vec<R>]) -> Vec<Vec<R>> {
+	f.iter().map(|v| {
+		(0..g[0].len()).map(|i| v.iter().zip(g.iter()).fold(R::zero(), |acc, (c, col)| acc + c.clone() * col[i].clone())).collect()
+	}).collect()
+}
+
+#[cfg(test)]
+fn zz_matrix(columns : &[&[i128]]) -> Vec<Vec<crate::algebra::num::ZZ>> {
+	use crate::algebra::num::ZZ;
+	columns.iter().map(|c| c.iter().map(|x| ZZ::from(*x)).collect()).collect()
+}
+
+#[test] fn exterior_algebra() {
+	use crate::algebra::num::ZZ;
+	type E = ExteriorAlgebra<ZZ>;
+	let (e0, e1, e2) = (E::basis_vector(0), E::basis_vector(1), E::basis_vector(2));
+	assert!(e0.wedge(&e0).is_zero());
+	assert_eq!(e1.wedge(&e0), -e0.wedge(&e1));
+	assert_eq!(E::from_indices(&[2, 0, 1], ZZ::one()), e0.wedge(&e1).wedge(&e2));
+	assert!(E::from_indices(&[1, 2, 1], ZZ::one()).is_zero());
+	// two-forms commute
+	let (a, b) = (e0.wedge(&e1), e2.wedge(&E::basis_vector(3)));
+	assert_eq!(a.clone() * b.clone(), b * a);
+	assert_eq!(E::basis(5, 2).len(), 10);
+	assert_eq!((e0.clone() + e1.clone()).sca_mul(ZZ::from(3)).coordinates(3, 1), vec![ZZ::from(3), ZZ::from(3), ZZ::zero()]);
+
+	// the columns (1, 2, 0), (0, 1, 3), (2, 0, 1)
+	let f = zz_matrix(&[&[1, 2, 0], &[0, 1, 3], &[2, 0, 1]]);
+	// the top power is the determinant
+	assert_eq!(exterior_power(&f, 3, 3), vec![vec![ZZ::from(13)]]);
+	// Λ^2 f (e_0 ∧ e_1) has the minors of the first two columns
+	assert_eq!(exterior_power(&f, 3, 2)[0], vec![ZZ::from(1), ZZ::from(3), ZZ::from(6)]);
+	let g = zz_matrix(&[&[0, 1, 1], &[1, -1, 2], &[4, 0, -3]]);
+	for k in 0..=3 {
+		assert_eq!(exterior_power(&compose(&g, &f), 3, k), compose(&exterior_power(&g, 3, k), &exterior_power(&f, 3, k)));
+	}
+}
+
+#[test] fn symmetric_algebra() {
+	use crate::algebra::num::ZZ;
+	type S = SymmetricAlgebra<ZZ>;
+	let (e0, e1) = (S::basis_vector(0), S::basis_vector(1));
+	assert_eq!(e1.clone() * e0.clone(), e0.clone() * e1.clone());
+	assert_eq!((e0.clone() + e1.clone()).pow(2), S::from_indices(&[0, 0], ZZ::one()) + S::from_indices(&[1, 0], ZZ::from(2)) + S::from_indices(&[1, 1], ZZ::one()));
+	assert_eq!(S::basis(3, 2).len(), 6);
+	assert_eq!((e0.clone() + S::one()).homogeneous(1), e0);
+
+	// Sym^2 of diag(2, 3) is diag(4, 6, 9)
+	let d = zz_matrix(&[&[2, 0], &[0, 3]]);
+	assert_eq!(symmetric_power(&d, 2, 2), zz_matrix(&[&[4, 0, 0], &[0, 6, 0], &[0, 0, 9]]));
+	let f = zz_matrix(&[&[1, 2, 0], &[0, 1, 3]]);
+	let g = zz_matrix(&[&[1, 1], &[-1, 2], &[0, 5]]);
+	for k in 0..=3 {
+		assert_eq!(symmetric_power(&compose(&g, &f), 2, k), compose(&symmetric_power(&g, 2, k), &symmetric_power(&f, 3, k)));
+	}
+}
@@ -0,0 +1,250 @@
+//! approximate values with rigorous error bounds, e.g. for plotting exact results
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::algebra::{Ring, Var, UniPolynomial, MultiPolynomial};
+#[cfg(test)]
+use crate::algebra::AddGroup;
+
+pub trait Numeric {
+	/// the nearest `f64`, ties to even
+	fn numeric(&self) -> f64;
+	/// an interval containing the exact value
+	fn enclosure(&self) -> Interval;
+}
+
+/// the nearest `f64` to `n / d`, ties to even, where `d > 0`
+pub(crate) fn round_quotient(n : i128, d : i128) -> f64 {
+	if n.unsigned_abs() < (1 << 53) && d < (1 << 53) {
+		// both are exact, and IEEE division is correctly rounded
+		return n as f64 / d as f64;
+	}
+	let (p, q) = (n.unsigned_abs(), d as u128);
+	let (mut m, mut r, mut e) = (p / q, p % q, 0i32);
+	// long division until m has 54 significant bits: 53 for the mantissa and the rounding bit
+	while m < (1 << 53) {
+		r <<= 1;
+		m <<= 1;
+		if r >= q {
+			m |= 1;
+			r -= q;
+		}
+		e -= 1;
+	}
+	let mut sticky = r != 0;
+	while m >= (1 << 54) {
+		sticky |= m & 1 == 1;
+		m >>= 1;
+		e += 1;
+	}
+	let mut mantissa = m >> 1;
+	if m & 1 == 1 && (sticky || mantissa & 1 == 1) {
+		mantissa += 1;
+	}
+	let value = mantissa as f64 * 2f64.powi(e + 1);
+	if n < 0 { -value } else { value }
+}
+
+/// `(s, e)` with `s = a + b` rounded and `e` the exact error, by TwoSum
+fn two_sum(a : f64, b : f64) -> (f64, f64) {
+	let s = a + b;
+	let bb = s - a;
+	(s, (a - (s - bb)) + (b - bb))
+}
+/// round `x + error` down, where `x` is the rounded result
+fn down(x : f64, error : f64) -> f64 {
+	if error < 0.0 { x.next_down() } else { x }
+}
+/// round `x + error` up, where `x` is the rounded result
+fn up(x : f64, error : f64) -> f64 {
+	if error > 0.0 { x.next_up() } else { x }
+}
+/// the rounded product and the exact error, which fma computes without rounding
+fn two_product(a : f64, b : f64) -> (f64, f64) {
+	let p = a * b;
+	(p, a.mul_add(b, -p))
+}
+
+/// a closed interval `[lower, upper]` of `f64`, the arithmetic rounds outwards so that the exact result is always contained
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+	lower : f64,
+	upper : f64,
+}
+impl Interval {
+	pub fn new(lower : f64, upper : f64) -> Interval {
+		if lower <= upper {
+			Interval { lower, upper }
+		} else {
+			panic!("Interval: lower bound {} should not be greater than upper bound {}", lower, upper)
+		}
+	}
+
+	pub fn point(x : f64) -> Interval {
+		Interval::new(x, x)
+	}
+
+	/// the smallest interval containing `x` and its two neighbours
+	fn around(x : f64) -> Interval {
+		Interval::new(x.next_down(), x.next_up())
+	}
+
+	pub fn lower(&self) -> f64 {
+		self.lower
+	}
+	pub fn upper(&self) -> f64 {
+		self.upper
+	}
+	pub fn width(&self) -> f64 {
+		self.upper - self.lower
+	}
+	pub fn midpoint(&self) -> f64 {
+		self.lower / 2.0 + self.upper / 2.0
+	}
+
+	pub fn contains(&self, x : f64) -> bool {
+		self.lower <= x && x <= self.upper
+	}
+
+	/// tighter than repeated multiplication, e.g. `[-1, 2]^2 = [0, 4]`
+	pub fn pow(&self, n : usize) -> Interval {
+		// for x >= 0
+		let pow_up = |x : f64| (0..n).fold(1.0, |acc : f64, _| {
+			let (p, e) = two_product(acc, x);
+			up(p, e)
+		});
+		let pow_down = |x : f64| (0..n).fold(1.0, |acc : f64, _| {
+			let (p, e) = two_product(acc, x);
+			down(p, e)
+		});
+		if n == 0 {
+			Interval::point(1.0)
+		} else if n % 2 == 1 {
+			let lower = if self.lower >= 0.0 { pow_down(self.lower) } else { -pow_up(-self.lower) };
+			let upper = if self.upper >= 0.0 { pow_up(self.upper) } else { -pow_down(-self.upper) };
+			Interval::new(lower, upper)
+		} else {
+			let (a, b) = (self.lower.abs(), self.upper.abs());
+			let least = if self.contains(0.0) { 0.0 } else { a.min(b) };
+			Interval::new(pow_down(least), pow_up(a.max(b)))
+		}
+	}
+}
+impl Add for Interval {
+	type Output = Interval;
+	fn add(self, rhs : Self) -> Self::Output {
+		let (l, el) = two_sum(self.lower, rhs.lower);
+		let (u, eu) = two_sum(self.upper, rhs.upper);
+		Interval::new(down(l, el), up(u, eu))
+	}
+}
+impl Neg for Interval {
+	type Output = Interval;
+	fn neg(self) -> Self::Output {
+		Interval::new(-self.upper, -self.lower)
+	}
+}
+impl Sub for Interval {
+	type Output = Interval;
+	fn sub(self, rhs : Self) -> Self::Output {
+		self + (-rhs)
+	}
+}
+impl Mul for Interval {
+	type Output = Interval;
+	fn mul(self, rhs : Self) -> Self::Output {
+		let products = [
+			two_product(self.lower, rhs.lower), two_product(self.lower, rhs.upper),
+			two_product(self.upper, rhs.lower), two_product(self.upper, rhs.upper),
+		];
+		Interval::new(
+			products.iter().map(|(p, e)| down(*p, *e)).fold(f64::INFINITY, f64::min),
+			products.iter().map(|(p, e)| up(*p, *e)).fold(f64::NEG_INFINITY, f64::max),
+		)
+	}
+}
+impl fmt::Display for Interval {
+	fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "[{}, {}]", self.lower, self.upper)
+	}
+}
+
+impl<R : Ring + Numeric> UniPolynomial<R> {
+	/// an interval containing `f(x)` for every `x` in `at`, by Horner's rule
+	pub fn eval_interval(&self, at : Interval) -> Interval {
+		self.iter().rev().fold(Interval::point(0.0), |acc, c| acc * at + c.enclosure())
+	}
+}
+impl<R : Ring + Numeric> MultiPolynomial<R> {
+	/// an interval containing `f(x)` for every point `x` in the box `at`
+	pub fn eval_interval(&self, at : &HashMap<Var, Interval>) -> Interval {
+		let values : HashMap<usize, Interval> = at.iter().map(|(v, x)| (v.index, *x)).collect();
+		self.iter().fold(Interval::point(0.0), |acc, (e, c)| {
+			acc + e.iter().enumerate().filter(|(_, n)| **n > 0).fold(c.enclosure(), |acc, (i, n)| {
+				acc * values.get(&i).expect("no such a variable!").pow(*n)
+			})
+		})
+	}
+}
+
+impl Interval {
+	/// `[x, x]` if `x` is exact, the neighbourhood of `x` otherwise
+	pub(crate) fn from_rounded(x : f64, exact : bool) -> Interval {
+		if exact { Interval::point(x) } else { Interval::around(x) }
+	}
+}
+
+#[test] fn correct_rounding() {
+	use crate::algebra::num::{ZZ, QQ};
+	assert_eq!(QQ::from_pair(1, 10).numeric(), 0.1);
+	assert_eq!(QQ::from_pair(-2, 3).numeric(), -2.0 / 3.0);
+	// ties to even
+	assert_eq!(ZZ::from((1 << 53) + 1).numeric(), (1u64 << 53) as f64);
+	assert_eq!(ZZ::from((1 << 53) + 3).numeric(), ((1u64 << 53) + 4) as f64);
+	assert_eq!(QQ::from_pair((1 << 53) + 1, 2).numeric(), (1u64 << 52) as f64);
+	assert_eq!(QQ::from_pair((1 << 53) + 3, 2).numeric(), ((1u64 << 52) + 2) as f64);
+	// just above a tie
+	assert_eq!(QQ::from_pair((1 << 54) + 2 + 1, 4).numeric(), ((1u64 << 52) + 1) as f64);
+	// large numerator and denominator
+	assert_eq!(QQ::from_pair(i128::MAX, i128::MAX - 1).numeric(), 1.0);
+	assert_eq!(QQ::from_pair(1, 3 << 100).numeric(), 1.0 / 3.0 / 2f64.powi(100));
+
+	let third = QQ::from_pair(1, 3).enclosure();
+	assert!(third.lower() < 1.0 / 3.0 && 1.0 / 3.0 < third.upper());
+	assert_eq!(ZZ::from(7).enclosure(), Interval::point(7.0));
+	assert_eq!(QQ::from_pair(3, 4).enclosure(), Interval::point(0.75));
+}
+
+#[test] fn interval_arithmetic() {
+	let a = Interval::new(-1.0, 2.0);
+	assert_eq!(a.pow(2), Interval::new(0.0, 4.0));
+	assert!((a * a).contains(-2.0));
+	assert!(Interval::new(-2.0, -1.0).pow(3).contains(-8.0));
+	// the float 0.1 added three times is not a float, but it is enclosed tightly
+	let tenth = Interval::point(0.1);
+	let sum = tenth + tenth + tenth;
+	assert!(sum.contains(0.1 * 3.0) && sum.lower() < sum.upper() && sum.width() < 1e-16);
+	assert_eq!(Interval::point(0.5) + Interval::point(0.25), Interval::point(0.75));
+}
+
+#[test] fn polynomial_enclosure() {
+	use crate::algebra::num::{ZZ, QQ};
+	use crate::algebra::VarTable;
+	// x^2 - 2 changes sign on [1.4, 1.5] only
+	let f = UniPolynomial::from(vec![ZZ::from(-2), ZZ::from(0), ZZ::from(1)]);
+	assert!(f.eval_interval(Interval::new(1.4, 1.5)).contains(0.0));
+	assert!(!f.eval_interval(Interval::new(1.5, 2.0)).contains(0.0));
+	// x / 3 at x = 3 is exactly one
+	let g = UniPolynomial::from(vec![QQ::zero(), QQ::from_pair(1, 3)]);
+	assert!(g.eval_interval(Interval::point(3.0)).contains(1.0));
+
+	let mut vt = VarTable::new();
+	vt.push("x".into());
+	vt.push("y".into());
+	let (x, y) = (Var::from_index(0, &vt), Var::from_index(1, &vt));
+	let h = MultiPolynomial::<ZZ>::from_var(x).pow(2) - MultiPolynomial::from_var(y);
+	let value = h.eval_interval(&HashMap::from([(x, Interval::new(-1.0, 1.0)), (y, Interval::point(0.5))]));
+	assert!(value.contains(-0.5) && value.contains(0.5));
+}
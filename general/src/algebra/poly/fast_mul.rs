@@ -0,0 +1,352 @@
+//! multiplication of polynomials, on slices of coefficients with the lowest degree first
+//!
+//! `Ring::convolution` picks the algorithm: Karatsuba for every ring, and the number theoretic transform
+//! for `Fp<P>` with `P - 1` divisible by a large power of two and for `ZZ` (by CRT over four such primes)
+
+use crate::algebra::{AddGroup, Ring, Field};
+use crate::algebra::num::{Fp, ZZ};
+use crate::algebra::poly::UniPolynomial;
+
+/// below it, the schoolbook multiplication is faster than Karatsuba
+const KARATSUBA_THRESHOLD : usize = 32;
+/// below it, Karatsuba is faster than NTT
+const NTT_THRESHOLD : usize = 64;
+/// a polynomial is sparse if less than one in `SPARSE_RATIO` coefficients is nonzero
+const SPARSE_RATIO : usize = 4;
+
+fn add_into<R : Ring>(target : &mut [R], v : &[R]) {
+	for (t, e) in target.iter_mut().zip(v) {
+		*t = t.clone() + e.clone();
+	}
+}
+fn add_slices<R : Ring>(a : &[R], b : &[R]) -> Vec<R> {
+	let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+	let mut v = long.to_vec();
+	add_into(&mut v, short);
+	v
+}
+
+pub fn schoolbook<R : Ring>(lhs : &[R], rhs : &[R]) -> Vec<R> {
+	if lhs.is_empty() || rhs.is_empty() {
+		return Vec::new();
+	}
+	let mut v = vec![R::zero(); lhs.len() + rhs.len() - 1];
+	for (i, a) in lhs.iter().enumerate() {
+		for (j, b) in rhs.iter().enumerate() {
+			v[i + j] = v[i + j].clone() + a.clone() * b.clone();
+		}
+	}
+	v
+}
+
+/// `O(n^1.59)` multiplication over any ring, falls back to `schoolbook` for short inputs
+pub fn karatsuba<R : Ring>(lhs : &[R], rhs : &[R]) -> Vec<R> {
+	let (long, short) = if lhs.len() >= rhs.len() { (lhs, rhs) } else { (rhs, lhs) };
+	if short.len() < KARATSUBA_THRESHOLD {
+		return schoolbook(long, short);
+	}
+	let mut v = vec![R::zero(); long.len() + short.len() - 1];
+	if 2 * short.len() <= long.len() {
+		// unbalanced, cut the long one into pieces as long as the short one
+		for (n, chunk) in long.chunks(short.len()).enumerate() {
+			add_into(&mut v[n * short.len()..], &karatsuba(chunk, short));
+		}
+		return v;
+	}
+
+	let k = long.len() / 2;
+	let (a0, a1) = long.split_at(k);
+	let (b0, b1) = short.split_at(k);
+	let z0 = karatsuba(a0, b0);
+	let z2 = karatsuba(a1, b1);
+	let mut z1 = karatsuba(&add_slices(a0, a1), &add_slices(b0, b1));
+	for (n, e) in z1.iter_mut().enumerate() {
+		*e = e.clone() - z0.get(n).cloned().unwrap_or_else(R::zero) - z2.get(n).cloned().unwrap_or_else(R::zero);
+	}
+	add_into(&mut v, &z0);
+	add_into(&mut v[k..], &z1);
+	add_into(&mut v[2 * k..], &z2);
+	v
+}
+
+/// a generator of the multiplicative group of `Fp<P>`
+pub fn primitive_root<const P : u64>() -> Fp<P> {
+	let mut factors = Vec::new();
+	let (mut m, mut d) = (P - 1, 2);
+	while d * d <= m {
+		if m % d == 0 {
+			factors.push(d);
+			while m % d == 0 {
+				m /= d;
+			}
+		}
+		d += 1;
+	}
+	if m > 1 {
+		factors.push(m);
+	}
+	(2..P).map(|g| Fp::from(g as i128))
+		.find(|g : &Fp<P>| factors.iter().all(|q| !g.pow(((P - 1) / q) as usize).is_one()))
+		.unwrap_or_else(Fp::one)
+}
+
+/// `g^e` modulo `p`, usable in constants
+const fn pow_mod(mut g : u64, mut e : u64, p : u64) -> u64 {
+	let mut r = 1 % p;
+	g %= p;
+	while e > 0 {
+		if e & 1 == 1 {
+			r = ((r as u128 * g as u128) % p as u128) as u64;
+		}
+		g = ((g as u128 * g as u128) % p as u128) as u64;
+		e >>= 1;
+	}
+	r
+}
+
+/// a primitive `2^s`-th root of unity of `Fp<P>`, where `2^s` is the largest power of two dividing `P - 1`,
+/// computed once for each `P` when the crate is compiled
+struct TwoAdicRoot<const P : u64>;
+impl<const P : u64> TwoAdicRoot<P> {
+	/// the odd part of `P - 1` as a power of a quadratic non-residue, found by Euler's criterion,
+	/// so that unlike `primitive_root` no factorisation of `P - 1` is needed
+	const ROOT : u64 = {
+		let odd = (P - 1) >> (P - 1).trailing_zeros();
+		let mut g = 2;
+		// the least non-residue is small, the bound only stops the search for a composite `P`
+		while g < P && g < 1 << 16 && pow_mod(g, (P - 1) / 2, P) != P - 1 {
+			g += 1;
+		}
+		if g < P && g < 1 << 16 { pow_mod(g, odd, P) } else { 1 }
+	};
+}
+
+/// in-place iterative transform, `a.len()` should be a power of two and `root` a primitive `a.len()`-th root of unity
+fn ntt<const P : u64>(a : &mut [Fp<P>], root : Fp<P>) {
+	let n = a.len();
+	let mut j = 0;
+	for i in 1..n {
+		let mut bit = n >> 1;
+		while j & bit != 0 {
+			j ^= bit;
+			bit >>= 1;
+		}
+		j |= bit;
+		if i < j {
+			a.swap(i, j);
+		}
+	}
+	let mut len = 2;
+	while len <= n {
+		let w = root.pow(n / len);
+		for chunk in a.chunks_mut(len) {
+			let mut wi = Fp::one();
+			for i in 0..(len / 2) {
+				let (u, v) = (chunk[i], chunk[i + len / 2] * wi);
+				chunk[i] = u + v;
+				chunk[i + len / 2] = u - v;
+				wi = wi * w;
+			}
+		}
+		len <<= 1;
+	}
+}
+
+/// multiplication by the number theoretic transform, `None` if `P - 1` is not divisible by a large enough power of two
+pub fn ntt_convolution<const P : u64>(lhs : &[Fp<P>], rhs : &[Fp<P>]) -> Option<Vec<Fp<P>>> {
+	if lhs.is_empty() || rhs.is_empty() {
+		return Some(Vec::new());
+	}
+	let length = lhs.len() + rhs.len() - 1;
+	let n = length.next_power_of_two();
+	if !(P - 1).is_multiple_of(n as u64) {
+		return None;
+	}
+	// `n` divides `2^s`, so the `2^s`-th root to the power `2^s / n` is a primitive `n`-th root
+	let root = Fp::<P>::from(TwoAdicRoot::<P>::ROOT as i128).pow(((1u64 << (P - 1).trailing_zeros()) / n as u64) as usize);
+	let mut a = [lhs, &vec![Fp::zero(); n - lhs.len()]].concat();
+	let mut b = [rhs, &vec![Fp::zero(); n - rhs.len()]].concat();
+	ntt(&mut a, root);
+	ntt(&mut b, root);
+	for (x, y) in a.iter_mut().zip(b) {
+		*x = *x * y;
+	}
+	ntt(&mut a, root.inv());
+	let n_inv = Fp::from(n as i128).inv();
+	Some(a.into_iter().take(length).map(|e| e * n_inv).collect())
+}
+
+/// NTT when possible and worth it, Karatsuba otherwise
+pub fn fp_convolution<const P : u64>(lhs : &[Fp<P>], rhs : &[Fp<P>]) -> Vec<Fp<P>> {
+	if lhs.len().min(rhs.len()) >= NTT_THRESHOLD {
+		if let Some(v) = ntt_convolution(lhs, rhs) {
+			return v;
+		}
+	}
+	karatsuba(lhs, rhs)
+}
+
+// NTT-friendly primes, `P - 1` is divisible by `2^23` for all of them
+const P1 : u64 = 998244353;
+const P2 : u64 = 167772161;
+const P3 : u64 = 469762049;
+const P4 : u64 = 754974721;
+
+fn residues<const P : u64>(lhs : &[ZZ], rhs : &[ZZ]) -> Option<Vec<u64>> {
+	let to_fp = |v : &[ZZ]| v.iter().map(|e| Fp::<P>::from(**e)).collect::<Vec<_>>();
+	Some(ntt_convolution(&to_fp(lhs), &to_fp(rhs))?.into_iter().map(|e| *e).collect())
+}
+
+/// multiplication over `ZZ` by NTT modulo four primes and Garner's CRT reconstruction,
+/// `None` if the coefficients of the product might not be determined by their residues
+pub fn zz_ntt_convolution(lhs : &[ZZ], rhs : &[ZZ]) -> Option<Vec<ZZ>> {
+	let modulus = (P1 as i128) * (P2 as i128) * (P3 as i128) * (P4 as i128);
+	let max = |v : &[ZZ]| v.iter().map(|e| e.unsigned_abs()).max().unwrap_or(0);
+	let bound = max(lhs).checked_mul(max(rhs))?.checked_mul(lhs.len().min(rhs.len()) as u128)?;
+	if bound >= (modulus / 2) as u128 {
+		return None;
+	}
+
+	let r = [residues::<P1>(lhs, rhs)?, residues::<P2>(lhs, rhs)?, residues::<P3>(lhs, rhs)?, residues::<P4>(lhs, rhs)?];
+	// x = x1 + t2 p1 + t3 p1 p2 + t4 p1 p2 p3, where each t_i is solved modulo p_i
+	fn digit<const P : u64>(x : i128, residue : u64, radix : i128) -> i128 {
+		let t = (Fp::<P>::from(residue as i128) - Fp::from(x)) / Fp::from(radix);
+		*t as i128
+	}
+	Some((0..r[0].len()).map(|n| {
+		let mut x = r[0][n] as i128;
+		let mut radix = P1 as i128;
+		x += digit::<P2>(x, r[1][n], radix) * radix;
+		radix *= P2 as i128;
+		x += digit::<P3>(x, r[2][n], radix) * radix;
+		radix *= P3 as i128;
+		x += digit::<P4>(x, r[3][n], radix) * radix;
+		ZZ::from(if x > modulus / 2 { x - modulus } else { x })
+	}).collect())
+}
+
+/// NTT with CRT when it is worth it and the coefficients are small enough, Karatsuba otherwise
+pub fn zz_convolution(lhs : &[ZZ], rhs : &[ZZ]) -> Vec<ZZ> {
+	if lhs.len().min(rhs.len()) >= NTT_THRESHOLD {
+		if let Some(v) = zz_ntt_convolution(lhs, rhs) {
+			return v;
+		}
+	}
+	karatsuba(lhs, rhs)
+}
+
+/// how the coefficients of a univariate polynomial are best handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Representation {
+	Dense,
+	Sparse,
+}
+impl<R : Ring> UniPolynomial<R> {
+	/// the number of nonzero coefficients
+	pub fn support_size(&self) -> usize {
+		self.0.iter().filter(|e| !e.is_zero()).count()
+	}
+
+	/// `Sparse` if less than one in `SPARSE_RATIO` coefficients is nonzero
+	pub fn representation(&self) -> Representation {
+		if self.support_size() * SPARSE_RATIO < self.0.len() {
+			Representation::Sparse
+		} else {
+			Representation::Dense
+		}
+	}
+
+	/// the nonzero terms `(n, c)` standing for `c x^n`, with increasing `n`
+	pub fn to_sparse(&self) -> Vec<(usize, R)> {
+		self.0.iter().cloned().enumerate().filter(|(_, c)| !c.is_zero()).collect()
+	}
+
+	/// the inverse of `to_sparse`, repeated degrees are added up
+	pub fn from_sparse(terms : Vec<(usize, R)>) -> UniPolynomial<R> {
+		let mut v = vec![R::zero(); terms.iter().map(|(n, _)| n + 1).max().unwrap_or(0)];
+		for (n, c) in terms {
+			v[n] = v[n].clone() + c;
+		}
+		UniPolynomial::from(v)
+	}
+
+	/// multiply term by term, it only costs (number of terms)^2
+	pub(crate) fn sparse_mul(&self, rhs : &Self) -> UniPolynomial<R> {
+		let (lhs, rhs) = (self.to_sparse(), rhs.to_sparse());
+		let mut v = vec![R::zero(); self.0.len() + rhs.last().map_or(0, |(n, _)| *n)];
+		for (i, a) in lhs.iter() {
+			for (j, b) in rhs.iter() {
+				v[i + j] = v[i + j].clone() + a.clone() * b.clone();
+			}
+		}
+		UniPolynomial::from(v)
+	}
+}
+
+#[cfg(test)]
+fn pseudo_random(seed : u64, length : usize, range : i128) -> Vec<i128> {
+	let mut state = seed;
+	(0..length).map(|_| {
+		state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+		((state >> 33) as i128) % (2 * range + 1) - range
+	}).collect()
+}
+
+#[test] fn karatsuba_agrees() {
+	let zz = |v : Vec<i128>| v.into_iter().map(ZZ::from).collect::<Vec<_>>();
+	for (la, lb) in [(0, 5), (31, 33), (100, 100), (200, 77), (129, 250), (300, 20)] {
+		let a = zz(pseudo_random(la as u64, la, 1000));
+		let b = zz(pseudo_random(lb as u64 + 7, lb, 1000));
+		assert_eq!(karatsuba(&a, &b), schoolbook(&a, &b), "{} × {}", la, lb);
+	}
+}
+
+#[test] fn ntt_agrees() {
+	type F = Fp<998244353>;
+	let a : Vec<F> = pseudo_random(1, 300, 1 << 40).into_iter().map(F::from).collect();
+	let b : Vec<F> = pseudo_random(2, 170, 1 << 40).into_iter().map(F::from).collect();
+	assert_eq!(ntt_convolution(&a, &b), Some(schoolbook(&a, &b)));
+	assert_eq!(fp_convolution(&a, &b), schoolbook(&a, &b));
+	assert_eq!(primitive_root::<998244353>(), F::from(3));
+
+	// the roots of unity of order exactly `2^s`, for the primes of the CRT
+	fn two_adic<const P : u64>() {
+		let root = Fp::<P>::from(TwoAdicRoot::<P>::ROOT as i128);
+		let s = (P - 1).trailing_zeros();
+		assert_eq!(root.pow(1 << (s - 1)), -Fp::<P>::one());
+		assert!(root.pow(1 << s).is_one());
+	}
+	two_adic::<P1>();
+	two_adic::<P2>();
+	two_adic::<P3>();
+	two_adic::<P4>();
+	assert_eq!(TwoAdicRoot::<7>::ROOT, 6);
+
+	// 7 - 1 is not divisible by 4
+	type G = Fp<7>;
+	assert_eq!(ntt_convolution(&[G::one(); 3], &[G::one(); 3]), None);
+}
+
+#[test] fn zz_ntt_agrees() {
+	let zz = |v : Vec<i128>| v.into_iter().map(ZZ::from).collect::<Vec<_>>();
+	let a = zz(pseudo_random(3, 500, 1 << 40));
+	let b = zz(pseudo_random(4, 400, 1 << 40));
+	assert_eq!(zz_ntt_convolution(&a, &b), Some(schoolbook(&a, &b)));
+
+	// too large for the CRT, so that Karatsuba is used
+	let c = zz(vec![1 << 100; 70]);
+	assert_eq!(zz_ntt_convolution(&c, &a), None);
+}
+
+#[test] fn sparse_or_dense() {
+	let zz = |v : Vec<i128>| UniPolynomial::from(v.into_iter().map(ZZ::from).collect());
+	let f = UniPolynomial::from_sparse(vec![(0, ZZ::from(1)), (100, ZZ::from(2))]);
+	let g = UniPolynomial::from_sparse(vec![(3, ZZ::from(-1)), (50, ZZ::from(1))]);
+	assert_eq!(f.representation(), Representation::Sparse);
+	assert_eq!(zz(vec![1, 2, 3]).representation(), Representation::Dense);
+	assert_eq!(f.clone() * g.clone(), UniPolynomial::from(schoolbook(&f, &g)));
+	assert_eq!((f.clone() * g).to_sparse(), vec![(3, ZZ::from(-1)), (50, ZZ::from(1)), (103, ZZ::from(-2)), (150, ZZ::from(2))]);
+
+	let h = zz(pseudo_random(5, 1000, 1 << 20));
+	assert_eq!(h.clone() * h.clone(), UniPolynomial::from(schoolbook(&h, &h)));
+}
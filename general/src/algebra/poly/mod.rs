@@ -0,0 +1,437 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ops::{Add, Deref, Mul, Neg, Sub};
+use alias::BasicObject;
+
+use crate::algebra::{AddGroup, Ring, IntegralDomain, ScaMul, Module, Algebra, Var, MonomialFormat};
+#[cfg(test)]
+use crate::algebra::num::ZZ;
+
+pub mod resultant;
+pub use resultant::{resultant, discriminant, subresultant_chain};
+pub mod fast_mul;
+pub use fast_mul::Representation;
+pub mod roots;
+pub use roots::{aberth_ehrlich, RootFinding, Roots, Cluster};
+
+/// a univariate polynomial stored densely, `self[n]` is the coefficient of `x^n`
+///
+/// there are never zero coefficients at the end, so the zero polynomial is the empty vec
+#[derive(Debug, Clone, PartialEq, Eq, BasicObject)]
+pub struct UniPolynomial<R : Ring>(Vec<R>);
+impl<R : Ring> Deref for UniPolynomial<R> {
+	type Target = Vec<R>;
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+impl<R : Ring> UniPolynomial<R> {
+	pub fn from(mut v : Vec<R>) -> UniPolynomial<R> {
+		while let Some(true) = v.last().map(|e| e.is_zero()) {
+			v.pop();
+		}
+		UniPolynomial(v)
+	}
+
+	pub fn constant(c : R) -> UniPolynomial<R> {
+		UniPolynomial::from(vec![c])
+	}
+
+	/// `c * x^n`
+	pub fn monomial(c : R, n : usize) -> UniPolynomial<R> {
+		let mut v = vec![R::zero(); n];
+		v.push(c);
+		UniPolynomial::from(v)
+	}
+
+	/// `None` for the zero polynomial
+	pub fn degree(&self) -> Option<usize> {
+		self.0.len().checked_sub(1)
+	}
+
+	/// the coefficient of `x^n`
+	pub fn coef(&self, n : usize) -> R {
+		self.0.get(n).cloned().unwrap_or_else(R::zero)
+	}
+
+	/// zero for the zero polynomial
+	pub fn leading_coef(&self) -> R {
+		self.0.last().cloned().unwrap_or_else(R::zero)
+	}
+
+	/// multiply by `x^k`
+	pub fn shift(&self, k : usize) -> UniPolynomial<R> {
+		if self.is_zero() {
+			self.clone()
+		} else {
+			UniPolynomial([vec![R::zero(); k], self.0.clone()].concat())
+		}
+	}
+
+	pub fn derivative(&self) -> UniPolynomial<R> {
+		UniPolynomial::from(self.0.iter().enumerate().skip(1).map(|(n, c)| c.times(n)).collect())
+	}
+
+	/// evaluate by Horner's rule
+	pub fn eval(&self, x : &R) -> R {
+		self.0.iter().rev().fold(R::zero(), |acc, c| acc * x.clone() + c.clone())
+	}
+
+	/// return `(q, r)` such that `lc(g)^(deg f - deg g + 1) * f = q * g + r` with `deg r < deg g`
+	pub fn pseudo_div_rem(&self, g : &UniPolynomial<R>) -> (UniPolynomial<R>, UniPolynomial<R>) {
+		let n = g.degree().expect("the zero polynomial cannot be divisor");
+		let Some(m) = self.degree().filter(|m| *m >= n) else {
+			return (UniPolynomial::zero(), self.clone());
+		};
+		let lc = g.leading_coef();
+		let mut q = UniPolynomial::zero();
+		let mut r = self.clone();
+		for _ in 0..=(m - n) {
+			if let Some(d) = r.degree().filter(|d| *d >= n) {
+				let t = UniPolynomial::monomial(r.leading_coef(), d - n);
+				q = q.sca_mul(lc.clone()) + t.clone();
+				r = r.sca_mul(lc.clone()) - t * g.clone();
+			} else {
+				q = q.sca_mul(lc.clone());
+				r = r.sca_mul(lc.clone());
+			}
+		}
+		(q, r)
+	}
+
+	/// pseudo remainder, see `pseudo_div_rem`
+	pub fn prem(&self, g : &UniPolynomial<R>) -> UniPolynomial<R> {
+		self.pseudo_div_rem(g).1
+	}
+}
+impl<D : IntegralDomain> UniPolynomial<D> {
+	/// divide every coefficient by `c`, `None` if one of them is not divisible
+	pub fn exact_div_coef(&self, c : &D) -> Option<UniPolynomial<D>> {
+		Some(UniPolynomial(self.0.iter().map(|e| e.exact_div(c)).collect::<Option<_>>()?))
+	}
+}
+
+impl<R : Ring> Add for UniPolynomial<R> {
+	type Output = UniPolynomial<R>;
+	fn add(self, rhs : Self) -> Self::Output {
+		let (mut long, short) = if self.0.len() >= rhs.0.len() { (self.0, rhs.0) } else { (rhs.0, self.0) };
+		for (l, s) in long.iter_mut().zip(short) {
+			*l = l.clone() + s;
+		}
+		UniPolynomial::from(long)
+	}
+}
+impl<R : Ring> Neg for UniPolynomial<R> {
+	type Output = UniPolynomial<R>;
+	fn neg(self) -> Self::Output {
+		UniPolynomial(self.0.into_iter().map(|e| -e).collect())
+	}
+}
+impl<R : Ring> Sub for UniPolynomial<R> {
+	type Output = UniPolynomial<R>;
+	fn sub(self, rhs : Self) -> Self::Output {
+		self + (-rhs)
+	}
+}
+impl<R : Ring> Mul for UniPolynomial<R> {
+	type Output = UniPolynomial<R>;
+	fn mul(self, rhs : Self) -> Self::Output {
+		if self.is_zero() || rhs.is_zero() {
+			return UniPolynomial::zero();
+		}
+		match (self.representation(), rhs.representation()) {
+			(Representation::Dense, Representation::Dense) => UniPolynomial::from(R::convolution(&self.0, &rhs.0)),
+			_ => self.sparse_mul(&rhs),
+		}
+	}
+}
+impl<R : Ring> AddGroup for UniPolynomial<R> {
+	fn zero() -> Self {
+		UniPolynomial(Vec::new())
+	}
+}
+impl<R : Ring> Ring for UniPolynomial<R> {
+	fn one() -> Self {
+		UniPolynomial::constant(R::one())
+	}
+}
+impl<D : IntegralDomain> IntegralDomain for UniPolynomial<D> {
+	fn exact_div(&self, rhs : &Self) -> Option<Self> {
+		let n = rhs.degree()?;
+		let lc = rhs.leading_coef();
+		let mut q = UniPolynomial::zero();
+		let mut r = self.clone();
+		while let Some(d) = r.degree() {
+			if d < n {
+				return None;
+			}
+			let t = UniPolynomial::monomial(r.leading_coef().exact_div(&lc)?, d - n);
+			r = r - t.clone() * rhs.clone();
+			q = q + t;
+		}
+		Some(q)
+	}
+}
+impl<R : Ring> ScaMul<R> for UniPolynomial<R> {
+	fn sca_mul(self, sca : R) -> Self {
+		UniPolynomial::from(self.0.into_iter().map(|e| sca.clone() * e).collect())
+	}
+}
+impl<R : Ring> Module<R> for UniPolynomial<R> {}
+impl<R : Ring> Algebra<R> for UniPolynomial<R> {}
+
+#[test] fn uni_polynomial() {
+	let zz = |v : Vec<i128>| UniPolynomial::from(v.into_iter().map(ZZ::from).collect());
+	let f = zz(vec![1, 2, 1, 0, 0]);
+	assert_eq!(f.degree(), Some(2));
+	assert_eq!(f.clone() * zz(vec![-1, 1]), zz(vec![-1, -1, 1, 1]));
+	assert_eq!(f.eval(&ZZ::from(3)), ZZ::from(16));
+	assert_eq!(f.derivative(), zz(vec![2, 2]));
+	assert_eq!(f.exact_div(&zz(vec![1, 1])), Some(zz(vec![1, 1])));
+	assert_eq!(f.exact_div(&zz(vec![1, 2])), None);
+
+	// 2^2 (3x^3 + x + 1) = (6x - 3) (2x^2 + x) + (7x + 4)
+	let (q, r) = zz(vec![1, 1, 0, 3]).pseudo_div_rem(&zz(vec![0, 1, 2]));
+	assert_eq!((q, r), (zz(vec![-3, 6]), zz(vec![4, 7])));
+}
+
+type Exponent = Vec<usize>;
+fn trim(mut e : Exponent) -> Exponent {
+	while let Some(&0) = e.last() {
+		e.pop();
+	}
+	e
+}
+fn exponent_add(a : &Exponent, b : &Exponent) -> Exponent {
+	let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+	let mut e = long.clone();
+	for (l, s) in e.iter_mut().zip(short) {
+		*l += s;
+	}
+	e
+}
+/// `None` if `a` is not divisible by `b`
+fn exponent_sub(a : &Exponent, b : &Exponent) -> Option<Exponent> {
+	if b.len() > a.len() {
+		return None;
+	}
+	let mut e = a.clone();
+	for (l, s) in e.iter_mut().zip(b) {
+		*l = l.checked_sub(*s)?;
+	}
+	Some(trim(e))
+}
+
+/// a multivariate polynomial stored sparsely, as a map from exponent vectors to nonzero coefficients
+///
+/// the `n`-th entry of an exponent vector is the power of the variable with index `n` in its `VarTable`,
+/// exponent vectors have no zeros at the end, so that the order of keys is the lexicographic order of monomials
+#[derive(Debug, Clone, PartialEq, Eq, BasicObject)]
+pub struct MultiPolynomial<R : Ring>(BTreeMap<Exponent, R>);
+impl<R : Ring> Deref for MultiPolynomial<R> {
+	type Target = BTreeMap<Exponent, R>;
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+impl<R : Ring> MultiPolynomial<R> {
+	/// collect terms, repeated exponents are added up
+	pub fn from_terms(terms : impl IntoIterator<Item = (Exponent, R)>) -> MultiPolynomial<R> {
+		let mut m : BTreeMap<Exponent, R> = BTreeMap::new();
+		for (e, c) in terms {
+			let e = trim(e);
+			let c = match m.remove(&e) {
+				Some(old) => old + c,
+				None => c,
+			};
+			if !c.is_zero() {
+				m.insert(e, c);
+			}
+		}
+		MultiPolynomial(m)
+	}
+
+	pub fn constant(c : R) -> MultiPolynomial<R> {
+		MultiPolynomial::from_terms([(Vec::new(), c)])
+	}
+
+	/// the polynomial `var`
+	pub fn from_var(var : Var) -> MultiPolynomial<R> {
+		MultiPolynomial::from_format(&MonomialFormat::from(HashMap::from([(var, 1)])), R::one())
+	}
+
+	/// the polynomial `c * x_1^e_1 ... x_n^e_n`
+	pub fn from_format(fmt : &MonomialFormat, c : R) -> MultiPolynomial<R> {
+		let mut e = vec![0; fmt.keys().map(|v| v.index + 1).max().unwrap_or(0)];
+		for (v, n) in fmt.iter() {
+			e[v.index] = *n;
+		}
+		MultiPolynomial::from_terms([(e, c)])
+	}
+
+	/// the coefficient of `x_1^e_1 ... x_n^e_n`
+	pub fn coef(&self, e : &Exponent) -> R {
+		self.0.get(&trim(e.clone())).cloned().unwrap_or_else(R::zero)
+	}
+
+	/// the greatest term in the lexicographic order
+	pub fn leading_term(&self) -> Option<(&Exponent, &R)> {
+		self.0.last_key_value()
+	}
+
+	/// `None` for the zero polynomial
+	pub fn total_degree(&self) -> Option<usize> {
+		self.0.keys().map(|e| e.iter().sum()).max()
+	}
+
+	/// `None` for the zero polynomial
+	pub fn degree_in(&self, var : Var) -> Option<usize> {
+		self.0.keys().map(|e| e.get(var.index).cloned().unwrap_or(0)).max()
+	}
+
+	/// evaluate at a point, every variable appearing in `self` should be given a value
+	pub fn eval(&self, at : &HashMap<Var, R>) -> R {
+		let mut values = Vec::new();
+		for (v, x) in at.iter() {
+			if values.len() <= v.index {
+				values.resize(v.index + 1, None);
+			}
+			values[v.index] = Some(x);
+		}
+		self.0.iter().fold(R::zero(), |acc, (e, c)| {
+			acc + e.iter().enumerate().fold(c.clone(), |acc, (i, n)| {
+				if *n == 0 {
+					acc
+				} else if let Some(Some(x)) = values.get(i) {
+					acc * x.pow(*n)
+				} else {
+					panic!("no such a variable!")
+				}
+			})
+		})
+	}
+
+	/// view `self` as a polynomial in `var` whose coefficients are polynomials in the other variables
+	pub fn to_univariate(&self, var : Var) -> UniPolynomial<MultiPolynomial<R>> {
+		let mut v = vec![Vec::new(); self.degree_in(var).map_or(0, |d| d + 1)];
+		for (e, c) in self.0.iter() {
+			let mut e = e.clone();
+			let n = e.get_mut(var.index).map_or(0, std::mem::take);
+			v[n].push((e, c.clone()));
+		}
+		UniPolynomial::from(v.into_iter().map(MultiPolynomial::from_terms).collect())
+	}
+
+	/// the inverse of `to_univariate`
+	pub fn from_univariate(p : &UniPolynomial<MultiPolynomial<R>>, var : Var) -> MultiPolynomial<R> {
+		MultiPolynomial::from_terms(p.iter().enumerate().flat_map(|(n, c)| {
+			c.0.iter().map(move |(e, c)| {
+				let mut e = e.clone();
+				if e.len() <= var.index {
+					e.resize(var.index + 1, 0);
+				}
+				e[var.index] += n;
+				(e, c.clone())
+			})
+		}))
+	}
+}
+
+impl<R : Ring> Add for MultiPolynomial<R> {
+	type Output = MultiPolynomial<R>;
+	fn add(self, rhs : Self) -> Self::Output {
+		MultiPolynomial::from_terms(self.0.into_iter().chain(rhs.0))
+	}
+}
+impl<R : Ring> Neg for MultiPolynomial<R> {
+	type Output = MultiPolynomial<R>;
+	fn neg(self) -> Self::Output {
+		MultiPolynomial(self.0.into_iter().map(|(e, c)| (e, -c)).collect())
+	}
+}
+impl<R : Ring> Sub for MultiPolynomial<R> {
+	type Output = MultiPolynomial<R>;
+	fn sub(self, rhs : Self) -> Self::Output {
+		self + (-rhs)
+	}
+}
+impl<R : Ring> Mul for MultiPolynomial<R> {
+	type Output = MultiPolynomial<R>;
+	fn mul(self, rhs : Self) -> Self::Output {
+		MultiPolynomial::from_terms(self.0.iter().flat_map(|(a, c)| {
+			rhs.0.iter().map(move |(b, d)| (exponent_add(a, b), c.clone() * d.clone()))
+		}))
+	}
+}
+impl<R : Ring> AddGroup for MultiPolynomial<R> {
+	fn zero() -> Self {
+		MultiPolynomial(BTreeMap::new())
+	}
+}
+impl<R : Ring> Ring for MultiPolynomial<R> {
+	fn one() -> Self {
+		MultiPolynomial::constant(R::one())
+	}
+}
+impl<D : IntegralDomain> IntegralDomain for MultiPolynomial<D> {
+	/// division by leading terms in the lexicographic order, which is exact iff `rhs` divides `self`
+	fn exact_div(&self, rhs : &Self) -> Option<Self> {
+		let (le, lc) = rhs.leading_term()?;
+		let mut q = MultiPolynomial::zero();
+		let mut r = self.clone();
+		while let Some((e, c)) = r.leading_term() {
+			let t = MultiPolynomial::from_terms([(exponent_sub(e, le)?, c.exact_div(lc)?)]);
+			r = r - t.clone() * rhs.clone();
+			q = q + t;
+		}
+		Some(q)
+	}
+}
+impl<R : Ring> ScaMul<R> for MultiPolynomial<R> {
+	fn sca_mul(self, sca : R) -> Self {
+		MultiPolynomial::from_terms(self.0.into_iter().map(|(e, c)| (e, sca.clone() * c)))
+	}
+}
+impl<R : Ring> Module<R> for MultiPolynomial<R> {}
+impl<R : Ring> Algebra<R> for MultiPolynomial<R> {}
+
+#[test] fn multi_polynomial() {
+	use crate::algebra::VarTable;
+	let mut vt = VarTable::new();
+	vt.push("x".into());
+	vt.push("y".into());
+	let (x, y) = (Var::from_index(0, &vt), Var::from_index(1, &vt));
+	let px = MultiPolynomial::<ZZ>::from_var(x);
+	let py = MultiPolynomial::<ZZ>::from_var(y);
+	let c = |n : i128| MultiPolynomial::constant(ZZ::from(n));
+
+	// (x + y)(x - y) = x^2 - y^2
+	let f = (px.clone() + py.clone()) * (px.clone() - py.clone());
+	assert_eq!(f, px.pow(2) - py.pow(2));
+	assert_eq!(f.total_degree(), Some(2));
+	assert_eq!(f.degree_in(y), Some(2));
+	assert_eq!(f.coef(&vec![0, 2, 0]), ZZ::from(-1));
+	assert_eq!(f.eval(&HashMap::from([(x, ZZ::from(5)), (y, ZZ::from(3))])), ZZ::from(16));
+
+	assert_eq!(f.exact_div(&(px.clone() - py.clone())), Some(px.clone() + py.clone()));
+	assert_eq!(f.exact_div(&(px.clone() - c(1))), None);
+
+	// as a polynomial in y: -1 y^2 + 0 y + x^2
+	let u = f.to_univariate(y);
+	assert_eq!(u, UniPolynomial::from(vec![px.pow(2), c(0), c(-1)]));
+	assert_eq!(MultiPolynomial::from_univariate(&u, y), f);
+}
+#[test] fn poly_literal() {
+	use crate::algebra::{poly, VarTable, UndefinedVariable};
+	use crate::algebra::num::QQ;
+	let mut vt = VarTable::new();
+	vt.push("x".into());
+	vt.push("y".into());
+	let (x, y) = (Var::from_index(0, &vt), Var::from_index(1, &vt));
+	let px = MultiPolynomial::<QQ>::from_var(x);
+	let py = MultiPolynomial::<QQ>::from_var(y);
+
+	assert_eq!(poly!([x, y]; x*x - 2*x*y + y^2), (px.clone() - py.clone()).pow(2));
+	assert_eq!(poly!(vt; 1/2*x*2 - x), Ok(MultiPolynomial::zero()));
+	assert_eq!(poly!(&vt; -2/4*y^3), Ok(py.pow(3).sca_mul(QQ::from_pair(-1, 2))));
+	assert_eq!(poly!(vt; x + z), Err(UndefinedVariable("z".to_string())));
+}
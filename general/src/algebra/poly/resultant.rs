@@ -0,0 +1,230 @@
+use crate::algebra::{AddGroup, Ring, IntegralDomain, ScaMul, Var};
+use crate::algebra::poly::{UniPolynomial, MultiPolynomial};
+#[cfg(test)]
+use crate::algebra::num::ZZ;
+
+fn minus_one_pow<D : Ring>(n : usize) -> D {
+	if n.is_multiple_of(2) { D::one() } else { -D::one() }
+}
+
+impl<D : IntegralDomain> UniPolynomial<D> {
+	/// the subresultant PRS of `self` and `other`, where `deg self >= deg other > 0`
+	///
+	/// return `(prs, s)`: `prs = [f, g, S_(n_2 - 1), S_(n_3 - 1), ...]` with `n_i = deg prs[i]`,
+	/// and `s[i]` is the principal coefficient of `S_(n_i)`, i.e. the coefficient of `x^(n_i)` in it
+	fn subresultant_prs(&self, other : &Self) -> (Vec<Self>, Vec<D>) {
+		let (mut f, mut g) = (self.clone(), other.clone());
+		let mut m = g.degree().unwrap();
+		let mut d = f.degree().unwrap() - m;
+
+		let mut h = f.prem(&g).sca_mul(minus_one_pow(d + 1));
+		let mut lc = g.leading_coef();
+		let mut c = lc.pow(d);
+		let mut prs = vec![f.clone(), g.clone()];
+		let mut s = vec![D::one(), c.clone()];
+		c = -c;
+		while let Some(k) = h.degree() {
+			prs.push(h.clone());
+			(f, g, d, m) = (g, h, m - k, k);
+			let b = -lc * c.pow(d);
+			h = f.prem(&g).exact_div_coef(&b).expect("subresultant: not an integral domain");
+			lc = g.leading_coef();
+			c = if d > 1 {
+				(-lc.clone()).pow(d).exact_div(&c.pow(d - 1)).expect("subresultant: not an integral domain")
+			} else {
+				-lc.clone()
+			};
+			s.push(-c.clone());
+		}
+		(prs, s)
+	}
+
+	/// the subresultants `[S_0, S_1, ..., S_(k-1)]` of `self` and `other`, where `k = min(deg self, deg other)`
+	///
+	/// `S_j` is the determinant polynomial of the `j`-th Sylvester submatrix, with the rows of `self` above,
+	/// so that `S_0` is the resultant and the first nonzero `S_j` is (up to a constant) the gcd.
+	/// Only the subresultant PRS is computed, the other `S_j` follow from the structure theorem.
+	pub fn subresultant_chain(&self, other : &Self) -> Vec<Self> {
+		let (Some(m), Some(n)) = (self.degree(), other.degree()) else {
+			return Vec::new();
+		};
+		if m < n {
+			return other.subresultant_chain(self).into_iter().enumerate()
+				.map(|(j, p)| p.sca_mul(minus_one_pow((m - j) * (n - j))))
+				.collect();
+		}
+		let mut chain = vec![UniPolynomial::zero(); n];
+		if n == 0 {
+			return chain;
+		}
+		let (prs, s) = self.subresultant_prs(other);
+		for i in 2..prs.len() {
+			let d = prs[i - 1].degree().unwrap();
+			let e = prs[i].degree().unwrap();
+			chain[d - 1] = prs[i].clone();
+			if e + 1 < d {
+				// the defective case, `S_e` is similar to `S_(d-1)`
+				let delta = d - 1 - e;
+				chain[e] = prs[i].clone().sca_mul(prs[i].leading_coef().pow(delta))
+					.exact_div_coef(&s[i - 1].pow(delta))
+					.expect("subresultant: not an integral domain");
+			}
+		}
+		chain
+	}
+
+	/// `res(f, g)`, the determinant of the Sylvester matrix, zero if one of them is zero
+	pub fn resultant(&self, other : &Self) -> D {
+		match (self.degree(), other.degree()) {
+			(None, _) | (_, None) => D::zero(),
+			(Some(m), Some(0)) => other.leading_coef().pow(m),
+			(Some(0), Some(n)) => self.leading_coef().pow(n),
+			(Some(m), Some(n)) if m < n => other.resultant(self) * minus_one_pow(m * n),
+			_ => {
+				let (prs, s) = self.subresultant_prs(other);
+				if prs.last().unwrap().degree() == Some(0) {
+					s.last().unwrap().clone()
+				} else {
+					D::zero()
+				}
+			}
+		}
+	}
+
+	/// `disc(f) = (-1)^(n(n-1)/2) res(f, f') / lc(f)` with `n = deg f`
+	///
+	/// we assume the characteristic is zero (or greater than `n`) so that `deg f' = n - 1`
+	pub fn discriminant(&self) -> D {
+		let n = self.degree().filter(|n| *n > 0).expect("discriminant: the polynomial should not be constant");
+		let res = self.resultant(&self.derivative()) * minus_one_pow(n * (n - 1) / 2);
+		res.exact_div(&self.leading_coef()).expect("discriminant: not an integral domain")
+	}
+}
+
+/// `res_var(f, g)`, viewing `f` and `g` as polynomials in `var`
+pub fn resultant<D : IntegralDomain>(f : &MultiPolynomial<D>, g : &MultiPolynomial<D>, var : Var) -> MultiPolynomial<D> {
+	f.to_univariate(var).resultant(&g.to_univariate(var))
+}
+
+/// `disc_var(f)`, viewing `f` as a polynomial in `var`
+pub fn discriminant<D : IntegralDomain>(f : &MultiPolynomial<D>, var : Var) -> MultiPolynomial<D> {
+	f.to_univariate(var).discriminant()
+}
+
+/// the subresultants `[S_0, S_1, ...]` of `f` and `g` with respect to `var`, see `UniPolynomial::subresultant_chain`
+pub fn subresultant_chain<D : IntegralDomain>(f : &MultiPolynomial<D>, g : &MultiPolynomial<D>, var : Var) -> Vec<MultiPolynomial<D>> {
+	f.to_univariate(var).subresultant_chain(&g.to_univariate(var)).iter()
+		.map(|p| MultiPolynomial::from_univariate(p, var))
+		.collect()
+}
+
+/// the subresultants computed directly from the definition, by fraction-free elimination
+#[cfg(test)]
+fn subresultant_chain_by_determinant(f : &UniPolynomial<ZZ>, g : &UniPolynomial<ZZ>) -> Vec<UniPolynomial<ZZ>> {
+	fn det(mut a : Vec<Vec<ZZ>>) -> ZZ {
+		let n = a.len();
+		let (mut sign, mut prev) = (ZZ::one(), ZZ::one());
+		for k in 0..n {
+			let Some(p) = (k..n).find(|i| !a[*i][k].is_zero()) else {
+				return ZZ::zero();
+			};
+			if p != k {
+				a.swap(p, k);
+				sign = -sign;
+			}
+			for i in (k + 1)..n {
+				for j in (k + 1)..n {
+					a[i][j] = (a[i][j] * a[k][k] - a[i][k] * a[k][j]).exact_div(&prev).unwrap();
+				}
+			}
+			prev = a[k][k];
+		}
+		sign * a[n - 1][n - 1]
+	}
+
+	let (m, n) = (f.degree().unwrap(), g.degree().unwrap());
+	(0..m.min(n)).map(|j| {
+		// the rows x^(n-j-1) f, ..., f, x^(m-j-1) g, ..., g, written from the highest power x^(m+n-j-1)
+		let width = m + n - j;
+		let rows : Vec<Vec<ZZ>> = (0..(n - j)).rev().map(|k| f.shift(k))
+			.chain((0..(m - j)).rev().map(|k| g.shift(k)))
+			.map(|p| (0..width).rev().map(|power| p.coef(power)).collect())
+			.collect();
+		// keep the first m+n-2j-1 columns and the column of x^i
+		UniPolynomial::from((0..=j).map(|i| det(rows.iter().map(|r| {
+			[&r[..(m + n - 2 * j - 1)], &[r[width - 1 - i]]].concat()
+		}).collect())).collect())
+	}).collect()
+}
+
+#[test] fn resultant_univariate() {
+	let zz = |v : Vec<i128>| UniPolynomial::from(v.into_iter().map(ZZ::from).collect());
+
+	// res(x^2 - 1, x - 2) = g(1) g(-1) = 3
+	assert_eq!(zz(vec![-1, 0, 1]).resultant(&zz(vec![-2, 1])), ZZ::from(3));
+	assert_eq!(zz(vec![-2, 1]).resultant(&zz(vec![-1, 0, 1])), ZZ::from(3));
+	// common root
+	assert_eq!(zz(vec![-1, 0, 1]).resultant(&zz(vec![-1, 1])), ZZ::zero());
+	// res(2x + 1, 3) = 3
+	assert_eq!(zz(vec![1, 2]).resultant(&zz(vec![3])), ZZ::from(3));
+
+	// disc(x^2 + bx + c) = b^2 - 4c
+	assert_eq!(zz(vec![3, 5, 1]).discriminant(), ZZ::from(25 - 12));
+	// disc(x^3 + px + q) = -4p^3 - 27q^2
+	assert_eq!(zz(vec![5, -2, 0, 1]).discriminant(), ZZ::from(4 * 8 - 27 * 25));
+	// repeated root
+	assert_eq!((zz(vec![-1, 1]).pow(2) * zz(vec![2, 0, 3])).discriminant(), ZZ::zero());
+	// non monic: disc(a x^2 + b x + c) = b^2 - 4ac
+	assert_eq!(zz(vec![3, 5, 2]).discriminant(), ZZ::from(25 - 24));
+}
+
+#[test] fn subresultant_chain_univariate() {
+	let zz = |v : Vec<i128>| UniPolynomial::from(v.into_iter().map(ZZ::from).collect());
+	let pairs = vec![
+		// regular
+		(zz(vec![1, -3, 2, 5, 1]), zz(vec![2, 0, -1, 3])),
+		// equal degrees
+		(zz(vec![1, 2, 3]), zz(vec![4, 5, 6])),
+		// defective: a common factor of degree 2 and gaps in the degrees
+		(zz(vec![1, 0, 1]) * zz(vec![3, 1, 0, 0, 2]), zz(vec![1, 0, 1]) * zz(vec![-1, 2, 1])),
+		(zz(vec![1, 0, 0, 0, 0, 0, 1]), zz(vec![1, 0, 0, 1])),
+		(zz(vec![-5, 2, 8, -3, -3, 0, 1, 0, 1]), zz(vec![21, -9, -4, 0, 5, 0, 3])),
+		// the classical example of Knuth
+		(zz(vec![-5, 2, 8, -3, -3, 0, 1, 0, 1]), zz(vec![1, 0, 0, 0, 0, 0, 1])),
+		// lower degree first
+		(zz(vec![2, -1]), zz(vec![1, 1, 1, 1])),
+		(zz(vec![1, 1]) * zz(vec![2, 1, 0, 1]), zz(vec![1, 1]) * zz(vec![3, 0, 1])),
+	];
+	for (f, g) in pairs {
+		let chain = f.subresultant_chain(&g);
+		assert_eq!(chain, subresultant_chain_by_determinant(&f, &g), "{:?}, {:?}", f, g);
+		assert_eq!(f.resultant(&g), chain[0].coef(0));
+	}
+}
+
+#[test] fn resultant_multivariate() {
+	use crate::algebra::VarTable;
+	let mut vt = VarTable::new();
+	vt.push("x".into());
+	vt.push("y".into());
+	let (x, y) = (Var::from_index(0, &vt), Var::from_index(1, &vt));
+	let px = MultiPolynomial::<ZZ>::from_var(x);
+	let py = MultiPolynomial::<ZZ>::from_var(y);
+	let c = |n : i128| MultiPolynomial::constant(ZZ::from(n));
+
+	// eliminate y from x^2 + y^2 = 1, x = y
+	let circle = px.pow(2) + py.pow(2) - c(1);
+	let line = px.clone() - py.clone();
+	assert_eq!(resultant(&circle, &line, y), c(2) * px.pow(2) - c(1));
+
+	// the discriminant of a y^2 + b y + c in y
+	let (a, b) = (px.clone() + c(1), px.clone() * px.clone());
+	let f = a.clone() * py.pow(2) + b.clone() * py.clone() + c(3);
+	assert_eq!(discriminant(&f, y), b.pow(2) - c(12) * a);
+
+	// the last nonzero subresultant is the gcd, up to a factor free of y
+	let common = py.clone() + px.clone();
+	let chain = subresultant_chain(&(common.clone() * circle.clone()), &(common.clone() * (py.clone() - c(2))), y);
+	assert!(chain[0].is_zero());
+	assert_eq!(chain[1].exact_div(&common).map(|q| q.degree_in(y)), Some(Some(0)));
+}
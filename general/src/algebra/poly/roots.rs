@@ -0,0 +1,222 @@
+//! all complex roots of a univariate polynomial at once, by the Aberth–Ehrlich iteration
+
+use std::f64::consts::PI;
+
+use crate::algebra::{Ring, Numeric};
+use crate::algebra::complex::C64;
+use crate::algebra::poly::UniPolynomial;
+
+/// the parameters of `aberth_ehrlich`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootFinding {
+	pub max_iterations : usize,
+	/// a root is settled once its correction is below `tolerance` relative to itself
+	pub tolerance : f64,
+}
+impl Default for RootFinding {
+	fn default() -> RootFinding {
+		RootFinding {
+			max_iterations : 1000,
+			tolerance : 4.0 * f64::EPSILON,
+		}
+	}
+}
+
+/// the result of `aberth_ehrlich`, with the diagnostics of the iteration
+#[derive(Debug, Clone, PartialEq)]
+pub struct Roots {
+	/// the approximations, as many as the degree
+	pub roots : Vec<C64>,
+	/// the discs `D(roots[i], radii[i])` are inclusion discs (Braess–Hadeler):
+	/// every connected component of their union made of `k` discs contains exactly `k` roots
+	pub radii : Vec<f64>,
+	pub iterations : usize,
+	/// whether every root was settled before `max_iterations`
+	pub converged : bool,
+}
+
+/// a group of approximations whose inclusion discs overlap,
+/// it stands for a root of multiplicity `multiplicity`, or for that many roots close to each other
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cluster {
+	pub center : C64,
+	/// the disc `D(center, radius)` contains exactly `multiplicity` roots
+	pub radius : f64,
+	pub multiplicity : usize,
+}
+
+impl Roots {
+	/// the connected components of the union of inclusion discs
+	pub fn clusters(&self) -> Vec<Cluster> {
+		let n = self.roots.len();
+		// union-find
+		let mut parent : Vec<usize> = (0..n).collect();
+		fn find(parent : &mut [usize], i : usize) -> usize {
+			let mut i = i;
+			while parent[i] != i {
+				parent[i] = parent[parent[i]];
+				i = parent[i];
+			}
+			i
+		}
+		for i in 0..n {
+			for j in (i + 1)..n {
+				if (self.roots[i] - self.roots[j]).abs() <= self.radii[i] + self.radii[j] {
+					let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+					parent[a] = b;
+				}
+			}
+		}
+
+		let mut groups : Vec<(usize, Vec<usize>)> = Vec::new();
+		for i in 0..n {
+			let root = find(&mut parent, i);
+			match groups.iter_mut().find(|(r, _)| *r == root) {
+				Some((_, members)) => members.push(i),
+				None => groups.push((root, vec![i])),
+			}
+		}
+		groups.into_iter().map(|(_, members)| {
+			let k = members.len();
+			let center = members.iter().fold(C64::ZERO, |acc, i| acc + self.roots[*i]) * (1.0 / k as f64);
+			let radius = members.iter().map(|i| (self.roots[*i] - center).abs() + self.radii[*i]).fold(0.0, f64::max);
+			Cluster { center, radius, multiplicity : k }
+		}).collect()
+	}
+}
+
+/// `(p(z), p'(z), bound)` by Horner's rule, where `bound` bounds the rounding error of `p(z)`
+fn horner(coefs : &[C64], z : C64) -> (C64, C64, f64) {
+	let (mut p, mut dp, mut abs_p) = (C64::ZERO, C64::ZERO, 0.0);
+	let r = z.abs();
+	for c in coefs.iter().rev() {
+		dp = dp * z + p;
+		p = p * z + *c;
+		abs_p = abs_p * r + c.abs();
+	}
+	(p, dp, 4.0 * coefs.len() as f64 * f64::EPSILON * abs_p)
+}
+
+/// all complex roots of `coefs[0] + coefs[1] x + ... + coefs[n] x^n`, counted with multiplicity
+///
+/// the roots at zero are split off and returned exactly, the others start on a circle around
+/// the centroid of the roots and are refined simultaneously
+pub fn aberth_ehrlich(coefs : &[C64], options : &RootFinding) -> Roots {
+	let end = coefs.iter().rposition(|c| *c != C64::ZERO).map_or(0, |n| n + 1);
+	let start = coefs[..end].iter().position(|c| *c != C64::ZERO).unwrap_or(0);
+	let coefs = &coefs[start..end];
+	let mut result = Roots {
+		roots : vec![C64::ZERO; start],
+		radii : vec![0.0; start],
+		iterations : 0,
+		converged : true,
+	};
+	let n = coefs.len().saturating_sub(1);
+	if n == 0 {
+		return result;
+	}
+
+	// the Fujiwara bound of the roots, and a circle of half its radius around the centroid
+	let lc = coefs[n];
+	let bound = 2.0 * (1..=n).map(|k| (coefs[n - k] / lc).abs().powf(1.0 / k as f64)).fold(0.0, f64::max);
+	let center = -(coefs[n - 1] / lc) * (1.0 / n as f64);
+	let mut z : Vec<C64> = (0..n).map(|k| center + C64::from_polar(bound / 2.0, 2.0 * PI * k as f64 / n as f64 + 0.4)).collect();
+
+	let mut settled = vec![false; n];
+	while result.iterations < options.max_iterations && settled.iter().any(|s| !s) {
+		result.iterations += 1;
+		for i in 0..n {
+			if settled[i] {
+				continue;
+			}
+			let (p, dp, error) = horner(coefs, z[i]);
+			if p.abs() <= error {
+				// p(z) is below the rounding error, there is nothing more to gain
+				settled[i] = true;
+				continue;
+			}
+			let ratio = p / dp;
+			let repulsion = (0..n).filter(|j| *j != i).fold(C64::ZERO, |acc, j| acc + C64::ONE / (z[i] - z[j]));
+			let w = ratio / (C64::ONE - ratio * repulsion);
+			if !w.is_finite() {
+				continue;
+			}
+			z[i] = z[i] - w;
+			settled[i] = w.abs() <= options.tolerance * z[i].abs();
+		}
+	}
+	result.converged = settled.iter().all(|s| *s);
+
+	// Weierstrass corrections with the rounding error of p, give the inclusion radii n |W_i|
+	for i in 0..n {
+		let (p, _, error) = horner(coefs, z[i]);
+		let product = (0..n).filter(|j| *j != i).fold(lc, |acc, j| acc * (z[i] - z[j]));
+		result.radii.push(n as f64 * (p.abs() + error) / product.abs());
+	}
+	result.roots.extend(z);
+	result
+}
+
+impl<R : Ring + Numeric> UniPolynomial<R> {
+	/// all complex roots, from the nearest `f64` to every coefficient
+	pub fn complex_roots(&self) -> Roots {
+		let coefs : Vec<C64> = self.iter().map(|c| C64::from(c.numeric())).collect();
+		aberth_ehrlich(&coefs, &RootFinding::default())
+	}
+}
+
+#[cfg(test)]
+fn close_to(roots : &Roots, expected : &[C64], eps : f64) -> bool {
+	expected.iter().all(|e| roots.roots.iter().any(|z| (*z - *e).abs() < eps))
+}
+
+#[test] fn simple_roots() {
+	use crate::algebra::num::{ZZ, QQ};
+	let zz = |v : Vec<i128>| UniPolynomial::from(v.into_iter().map(ZZ::from).collect());
+
+	let roots = zz(vec![1, 0, 1]).complex_roots();
+	assert!(roots.converged);
+	assert!(close_to(&roots, &[C64::I, -C64::I], 1e-14));
+	assert_eq!(roots.clusters().len(), 2);
+
+	// x^3 - 2 x^2 has a double root at 0
+	let roots = zz(vec![0, 0, -2, 1]).complex_roots();
+	assert_eq!(roots.roots.len(), 3);
+	assert!(close_to(&roots, &[C64::ZERO, C64::from(2.0)], 1e-14));
+
+	// 1/2 x^2 - 1/8
+	let roots = UniPolynomial::from(vec![QQ::from_pair(-1, 8), QQ::from_pair(0, 1), QQ::from_pair(1, 2)]).complex_roots();
+	assert!(close_to(&roots, &[C64::from(0.5), C64::from(-0.5)], 1e-14));
+
+	// the 12th roots of unity, with f64 coefficients
+	let mut coefs = vec![C64::ZERO; 13];
+	coefs[0] = C64::from(-1.0);
+	coefs[12] = C64::ONE;
+	let roots = aberth_ehrlich(&coefs, &RootFinding::default());
+	assert!(roots.converged);
+	assert!(close_to(&roots, &(0..12).map(|k| C64::from_polar(1.0, PI * k as f64 / 6.0)).collect::<Vec<_>>(), 1e-13));
+	// every inclusion disc contains a root
+	assert!(roots.roots.iter().zip(roots.radii.iter()).all(|(z, r)| (z.abs() - 1.0).abs() <= *r));
+}
+
+#[test] fn wilkinson() {
+	use crate::algebra::num::ZZ;
+	let f = (1..=10).fold(UniPolynomial::one(), |acc, k| acc * UniPolynomial::from(vec![ZZ::from(-k), ZZ::from(1)]));
+	let roots = f.complex_roots();
+	assert!(roots.converged);
+	assert!(close_to(&roots, &(1..=10).map(|k| C64::from(k as f64)).collect::<Vec<_>>(), 1e-8));
+	assert!(roots.clusters().iter().all(|c| c.multiplicity == 1));
+}
+
+#[test] fn multiple_roots() {
+	use crate::algebra::num::ZZ;
+	// (x - 1)^3 (x + 2)
+	let f = UniPolynomial::from(vec![ZZ::from(-1), ZZ::from(1)]).pow(3) * UniPolynomial::from(vec![ZZ::from(2), ZZ::from(1)]);
+	let roots = f.complex_roots();
+	let mut clusters = roots.clusters();
+	clusters.sort_by_key(|c| c.multiplicity);
+	assert_eq!(clusters.iter().map(|c| c.multiplicity).collect::<Vec<_>>(), vec![1, 3]);
+	assert!((clusters[0].center - C64::from(-2.0)).abs() < 1e-12);
+	assert!((clusters[1].center - C64::ONE).abs() < 1e-4);
+	assert!(clusters[1].radius < 1e-3);
+}
@@ -1,7 +1,36 @@
+// lets `#[derive(MathClass)]`, which expands to `general::MathClass`, be used in this crate
+extern crate self as general;
+
 pub mod algebra;
+pub mod number_theory;
+pub mod linear_programming;
+pub mod root_system;
+pub mod poset;
 
 pub trait MathClass {
+	/// what is reported when the criteria fail
+	type Error : std::error::Error;
 	/// criteria for a math concept
-	fn check(&self) -> Result<(), String>;
+	fn check(&self) -> Result<(), Self::Error>;
+}
+/// a failed criterion described in words, for the classes without a more specific error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvariantError(pub String);
+impl std::fmt::Display for InvariantError {
+	fn fmt(&self, f : &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+impl std::error::Error for InvariantError {}
+impl From<String> for InvariantError {
+	fn from(s : String) -> InvariantError {
+		InvariantError(s)
+	}
+}
+impl From<&str> for InvariantError {
+	fn from(s : &str) -> InvariantError {
+		InvariantError(s.to_string())
+	}
 }
+pub use general_marco::MathClass;
 
@@ -0,0 +1,376 @@
+//! exact linear programming by the two-phase simplex method with Bland's rule, over any ordered field
+
+use crate::algebra::OrderedField;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relation {
+	Le,
+	Ge,
+	Eq,
+}
+impl Relation {
+	fn flip(self) -> Relation {
+		match self {
+			Relation::Le => Relation::Ge,
+			Relation::Ge => Relation::Le,
+			Relation::Eq => Relation::Eq,
+		}
+	}
+	fn holds<F : OrderedField>(self, lhs : &F, rhs : &F) -> bool {
+		match self {
+			Relation::Le => lhs <= rhs,
+			Relation::Ge => lhs >= rhs,
+			Relation::Eq => lhs == rhs,
+		}
+	}
+}
+
+/// `coefs · x (relation) rhs`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint<F : OrderedField> {
+	pub coefs : Vec<F>,
+	pub relation : Relation,
+	pub rhs : F,
+}
+
+/// maximize `objective · x` subject to `constraints` and `x >= 0`
+///
+/// its dual is to minimize `y · rhs` subject to `y^T A >= objective`,
+/// where `y_i >= 0` for `Le`, `y_i <= 0` for `Ge` and `y_i` is free for `Eq`
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearProgram<F : OrderedField> {
+	pub objective : Vec<F>,
+	pub constraints : Vec<Constraint<F>>,
+}
+
+/// every answer comes with a certificate that can be checked without trusting the solver
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solution<F : OrderedField> {
+	/// `x` is optimal because `dual` is a feasible solution of the dual with the same value
+	Optimal { x : Vec<F>, value : F, dual : Vec<F> },
+	/// a Farkas certificate: `y` satisfies the sign conditions of the dual, `y^T A >= 0` and `y · rhs < 0`
+	Infeasible { certificate : Vec<F> },
+	/// `x` is feasible and so is `x + t ray` for every `t >= 0`, while the objective grows along `ray`
+	Unbounded { x : Vec<F>, ray : Vec<F> },
+}
+
+fn dot<F : OrderedField>(lhs : &[F], rhs : &[F]) -> F {
+	lhs.iter().zip(rhs.iter()).fold(F::zero(), |acc, (a, b)| acc + a.clone() * b.clone())
+}
+
+/// `rows[i]` is the `i`-th row of `B^{-1} [A | rhs]` for the current basis `B`
+struct Tableau<F : OrderedField> {
+	rows : Vec<Vec<F>>,
+	basis : Vec<usize>,
+}
+impl<F : OrderedField> Tableau<F> {
+	fn width(&self) -> usize {
+		self.rows.first().map_or(0, |row| row.len() - 1)
+	}
+
+	fn pivot(&mut self, r : usize, c : usize) {
+		let p = self.rows[r][c].inv();
+		for entry in self.rows[r].iter_mut() {
+			*entry = entry.clone() * p.clone();
+		}
+		let pivot_row = self.rows[r].clone();
+		for (i, row) in self.rows.iter_mut().enumerate() {
+			if i != r && !row[c].is_zero() {
+				let factor = row[c].clone();
+				for (entry, p) in row.iter_mut().zip(pivot_row.iter()) {
+					*entry = entry.clone() - factor.clone() * p.clone();
+				}
+			}
+		}
+		self.basis[r] = c;
+	}
+
+	/// `cost[j] - c_B B^{-1} A_j`
+	fn reduced_cost(&self, cost : &[F], j : usize) -> F {
+		self.rows.iter().zip(self.basis.iter()).fold(cost[j].clone(), |acc, (row, b)| acc - cost[*b].clone() * row[j].clone())
+	}
+
+	/// `c_B B^{-1}`, read from the columns `first..` that held the identity at the start
+	fn multipliers(&self, cost : &[F], first : usize) -> Vec<F> {
+		(0..self.rows.len()).map(|i| {
+			self.rows.iter().zip(self.basis.iter()).fold(F::zero(), |acc, (row, b)| acc + cost[*b].clone() * row[first + i].clone())
+		}).collect()
+	}
+
+	fn objective(&self, cost : &[F]) -> F {
+		let w = self.width();
+		self.rows.iter().zip(self.basis.iter()).fold(F::zero(), |acc, (row, b)| acc + cost[*b].clone() * row[w].clone())
+	}
+
+	/// maximize `cost` letting only the columns `..allowed` enter the basis,
+	/// returns the entering column of an unbounded ray if there is one
+	///
+	/// by Bland's rule: the least improving column enters and ties in the ratio test leave by the least index,
+	/// so that the method never cycles
+	fn maximize(&mut self, cost : &[F], allowed : usize) -> Result<(), usize> {
+		let w = self.width();
+		loop {
+			let Some(c) = (0..allowed).find(|j| self.reduced_cost(cost, *j) > F::zero()) else {
+				return Ok(());
+			};
+			let mut leaving : Option<(usize, F)> = None;
+			for (i, row) in self.rows.iter().enumerate() {
+				if row[c] > F::zero() {
+					let ratio = row[w].clone() / row[c].clone();
+					let better = match &leaving {
+						None => true,
+						Some((r, best)) => ratio < *best || (ratio == *best && self.basis[i] < self.basis[*r]),
+					};
+					if better {
+						leaving = Some((i, ratio));
+					}
+				}
+			}
+			match leaving {
+				Some((r, _)) => self.pivot(r, c),
+				None => return Err(c),
+			}
+		}
+	}
+
+	/// the values of the first `n` variables at the current basic solution
+	fn point(&self, n : usize) -> Vec<F> {
+		let w = self.width();
+		let mut x = vec![F::zero(); n];
+		for (row, b) in self.rows.iter().zip(self.basis.iter()) {
+			if *b < n {
+				x[*b] = row[w].clone();
+			}
+		}
+		x
+	}
+}
+
+impl<F : OrderedField> LinearProgram<F> {
+	pub fn new(objective : Vec<F>) -> LinearProgram<F> {
+		LinearProgram { objective, constraints : Vec::new() }
+	}
+
+	pub fn add_constraint(&mut self, coefs : Vec<F>, relation : Relation, rhs : F) {
+		if coefs.len() != self.objective.len() {
+			panic!("LinearProgram: a constraint on {} variables, but there are {}", coefs.len(), self.objective.len())
+		}
+		self.constraints.push(Constraint { coefs, relation, rhs });
+	}
+
+	pub fn solve(&self) -> Solution<F> {
+		let (n, m) = (self.objective.len(), self.constraints.len());
+		// rows with a negative right hand side are negated, so that the artificial basis is feasible
+		let signs : Vec<bool> = self.constraints.iter().map(|c| c.rhs < F::zero()).collect();
+		let k = self.constraints.iter().filter(|c| c.relation != Relation::Eq).count();
+		// the columns: the variables, a slack or surplus for each inequality, an artificial for each row
+		let width = n + k + m;
+		let mut rows = Vec::with_capacity(m);
+		let mut slack = n;
+		for (i, (constraint, negated)) in self.constraints.iter().zip(signs.iter()).enumerate() {
+			let sign = |x : &F| if *negated { -x.clone() } else { x.clone() };
+			let mut row = vec![F::zero(); width + 1];
+			for (entry, a) in row.iter_mut().zip(constraint.coefs.iter()) {
+				*entry = sign(a);
+			}
+			let relation = if *negated { constraint.relation.flip() } else { constraint.relation };
+			match relation {
+				Relation::Le => row[slack] = F::one(),
+				Relation::Ge => row[slack] = -F::one(),
+				Relation::Eq => {},
+			}
+			if relation != Relation::Eq {
+				slack += 1;
+			}
+			row[n + k + i] = F::one();
+			row[width] = sign(&constraint.rhs);
+			rows.push(row);
+		}
+		let mut tableau = Tableau { rows, basis : (n + k..width).collect() };
+		// the multipliers belong to the negated rows, undo that for the certificates
+		let unsign = |y : Vec<F>| -> Vec<F> {
+			y.into_iter().zip(signs.iter()).map(|(y, negated)| if *negated { -y } else { y }).collect()
+		};
+
+		// phase one: maximize minus the sum of the artificials, it is bounded by zero
+		let mut cost = vec![F::zero(); width];
+		for c in cost[n + k..].iter_mut() {
+			*c = -F::one();
+		}
+		let _ = tableau.maximize(&cost, n + k);
+		if tableau.objective(&cost) < F::zero() {
+			return Solution::Infeasible { certificate : unsign(tableau.multipliers(&cost, n + k)) };
+		}
+		// the artificials left in the basis are zero, swap them out where the row is not redundant
+		for r in 0..m {
+			if tableau.basis[r] >= n + k {
+				if let Some(c) = (0..n + k).find(|c| !tableau.rows[r][*c].is_zero()) {
+					tableau.pivot(r, c);
+				}
+			}
+		}
+
+		// phase two
+		let mut cost = vec![F::zero(); width];
+		cost[..n].clone_from_slice(&self.objective);
+		match tableau.maximize(&cost, n + k) {
+			Ok(()) => Solution::Optimal {
+				x : tableau.point(n),
+				value : tableau.objective(&cost),
+				dual : unsign(tableau.multipliers(&cost, n + k)),
+			},
+			Err(c) => {
+				let mut ray = vec![F::zero(); n];
+				if c < n {
+					ray[c] = F::one();
+				}
+				for (row, b) in tableau.rows.iter().zip(tableau.basis.iter()) {
+					if *b < n {
+						ray[*b] = -row[c].clone();
+					}
+				}
+				Solution::Unbounded { x : tableau.point(n), ray }
+			},
+		}
+	}
+
+	pub fn is_feasible(&self, x : &[F]) -> bool {
+		x.len() == self.objective.len()
+			&& x.iter().all(|x| *x >= F::zero())
+			&& self.constraints.iter().all(|c| c.relation.holds(&dot(&c.coefs, x), &c.rhs))
+	}
+
+	/// the sign conditions of the dual: `y_i >= 0` for `Le`, `y_i <= 0` for `Ge`
+	fn has_dual_signs(&self, y : &[F]) -> bool {
+		y.len() == self.constraints.len() && self.constraints.iter().zip(y.iter()).all(|(c, y)| match c.relation {
+			Relation::Le => *y >= F::zero(),
+			Relation::Ge => *y <= F::zero(),
+			Relation::Eq => true,
+		})
+	}
+
+	/// `y^T A`
+	fn combine(&self, y : &[F]) -> Vec<F> {
+		(0..self.objective.len()).map(|j| {
+			self.constraints.iter().zip(y.iter()).fold(F::zero(), |acc, (c, y)| acc + y.clone() * c.coefs[j].clone())
+		}).collect()
+	}
+
+	/// whether `y` is feasible for the dual, and then `y · rhs` bounds the objective from above
+	pub fn is_dual_feasible(&self, y : &[F]) -> bool {
+		self.has_dual_signs(y) && self.combine(y).iter().zip(self.objective.iter()).all(|(a, c)| a >= c)
+	}
+
+	pub fn dual_value(&self, y : &[F]) -> F {
+		self.constraints.iter().zip(y.iter()).fold(F::zero(), |acc, (c, y)| acc + y.clone() * c.rhs.clone())
+	}
+
+	/// whether `y` proves that there is no feasible point
+	pub fn is_infeasibility_certificate(&self, y : &[F]) -> bool {
+		self.has_dual_signs(y) && self.combine(y).iter().all(|a| *a >= F::zero()) && self.dual_value(y) < F::zero()
+	}
+
+	/// whether the objective grows along `ray` without leaving the feasible region
+	pub fn is_unbounded_ray(&self, ray : &[F]) -> bool {
+		ray.len() == self.objective.len()
+			&& ray.iter().all(|d| *d >= F::zero())
+			&& self.constraints.iter().all(|c| c.relation.holds(&dot(&c.coefs, ray), &F::zero()))
+			&& dot(&self.objective, ray) > F::zero()
+	}
+}
+
+/// the coefficients, the relation and the right hand side, as fractions
+#[cfg(test)]
+type QQConstraint<'a> = (&'a [(i128, i128)], Relation, (i128, i128));
+#[cfg(test)]
+fn qq_program(objective : &[(i128, i128)], constraints : &[QQConstraint]) -> LinearProgram<crate::algebra::num::QQ> {
+	use crate::algebra::num::QQ;
+	let qq = |v : &[(i128, i128)]| v.iter().map(|(a, b)| QQ::from_pair(*a, *b)).collect::<Vec<_>>();
+	let mut lp = LinearProgram::new(qq(objective));
+	for (coefs, relation, (a, b)) in constraints {
+		lp.add_constraint(qq(coefs), *relation, QQ::from_pair(*a, *b));
+	}
+	lp
+}
+
+#[test] fn optimal_solutions() {
+	use crate::algebra::num::QQ;
+	use Relation::*;
+	// maximize 3x + 2y with x + y <= 4, x + 3y <= 6, x <= 3
+	let lp = qq_program(&[(3, 1), (2, 1)], &[
+		(&[(1, 1), (1, 1)], Le, (4, 1)),
+		(&[(1, 1), (3, 1)], Le, (6, 1)),
+		(&[(1, 1), (0, 1)], Le, (3, 1)),
+	]);
+	match lp.solve() {
+		Solution::Optimal { x, value, dual } => {
+			assert_eq!(x, vec![QQ::from_pair(3, 1), QQ::from_pair(1, 1)]);
+			assert_eq!(value, QQ::from_pair(11, 1));
+			assert!(lp.is_feasible(&x) && lp.is_dual_feasible(&dual));
+			assert_eq!(lp.dual_value(&dual), value);
+		},
+		other => panic!("expected an optimum, got {:?}", other),
+	}
+
+	// equalities, surplus and negative right hand sides: maximize -x - y with x + 2y = 3, x - y >= -3/2, -x <= -1/2
+	let lp = qq_program(&[(-1, 1), (-1, 1)], &[
+		(&[(1, 1), (2, 1)], Eq, (3, 1)),
+		(&[(1, 1), (-1, 1)], Ge, (-3, 2)),
+		(&[(-1, 1), (0, 1)], Le, (-1, 2)),
+	]);
+	match lp.solve() {
+		Solution::Optimal { x, value, dual } => {
+			assert_eq!(x, vec![QQ::from_pair(1, 2), QQ::from_pair(5, 4)]);
+			assert_eq!(value, QQ::from_pair(-7, 4));
+			assert!(lp.is_feasible(&x) && lp.is_dual_feasible(&dual));
+			assert_eq!(lp.dual_value(&dual), value);
+		},
+		other => panic!("expected an optimum, got {:?}", other),
+	}
+
+	// Beale's example cycles with the textbook pivoting rule
+	let lp = qq_program(&[(3, 4), (-20, 1), (1, 2), (-6, 1)], &[
+		(&[(1, 4), (-8, 1), (-1, 1), (9, 1)], Le, (0, 1)),
+		(&[(1, 2), (-12, 1), (-1, 2), (3, 1)], Le, (0, 1)),
+		(&[(0, 1), (0, 1), (1, 1), (0, 1)], Le, (1, 1)),
+	]);
+	match lp.solve() {
+		Solution::Optimal { value, dual, .. } => {
+			assert_eq!(value, QQ::from_pair(5, 4));
+			assert!(lp.is_dual_feasible(&dual));
+		},
+		other => panic!("expected an optimum, got {:?}", other),
+	}
+}
+
+#[test] fn infeasible_or_unbounded() {
+	use Relation::*;
+	// x + y <= 1 and x + y >= 3
+	let lp = qq_program(&[(1, 1), (1, 1)], &[
+		(&[(1, 1), (1, 1)], Le, (1, 1)),
+		(&[(1, 1), (1, 1)], Ge, (3, 1)),
+	]);
+	match lp.solve() {
+		Solution::Infeasible { certificate } => assert!(lp.is_infeasibility_certificate(&certificate)),
+		other => panic!("expected infeasibility, got {:?}", other),
+	}
+	// x - y = 1 with x, y >= 0 and y <= -1
+	let lp = qq_program(&[(0, 1), (0, 1)], &[
+		(&[(1, 1), (-1, 1)], Eq, (1, 1)),
+		(&[(0, 1), (1, 1)], Le, (-1, 1)),
+	]);
+	match lp.solve() {
+		Solution::Infeasible { certificate } => assert!(lp.is_infeasibility_certificate(&certificate)),
+		other => panic!("expected infeasibility, got {:?}", other),
+	}
+
+	// maximize x + y with x - y <= 1 and y - 2x <= 2
+	let lp = qq_program(&[(1, 1), (1, 1)], &[
+		(&[(1, 1), (-1, 1)], Le, (1, 1)),
+		(&[(-2, 1), (1, 1)], Le, (2, 1)),
+	]);
+	match lp.solve() {
+		Solution::Unbounded { x, ray } => assert!(lp.is_feasible(&x) && lp.is_unbounded_ray(&ray)),
+		other => panic!("expected unboundedness, got {:?}", other),
+	}
+}
@@ -0,0 +1,357 @@
+//! elementary number theory over `ZZ`: factorials, binomials, factorization and arithmetic functions
+
+use crate::algebra::{AddGroup, Ring};
+use crate::algebra::num::ZZ;
+
+pub fn gcd(a : ZZ, b : ZZ) -> ZZ {
+	ZZ::from(crate::algebra::num::gcd(*a, *b))
+}
+
+/// non-negative, and zero if either is zero
+pub fn lcm(a : ZZ, b : ZZ) -> ZZ {
+	if a.is_zero() || b.is_zero() {
+		ZZ::zero()
+	} else {
+		ZZ::from((*a / *gcd(a, b)).abs()) * ZZ::from(b.abs())
+	}
+}
+
+pub fn factorial(n : usize) -> ZZ {
+	(1..=n as i128).fold(ZZ::one(), |acc, k| acc * ZZ::from(k))
+}
+
+/// `n choose k`, for any integer `n` by `binomial(-n, k) = (-1)^k binomial(n + k - 1, k)`, and zero for `k < 0`
+pub fn binomial(n : ZZ, k : ZZ) -> ZZ {
+	let (n, k) = (*n, *k);
+	if k < 0 {
+		return ZZ::zero();
+	}
+	if n < 0 {
+		let value = binomial(ZZ::from(k - n - 1), ZZ::from(k));
+		return if k % 2 == 0 { value } else { -value };
+	}
+	if k > n {
+		return ZZ::zero();
+	}
+	let k = k.min(n - k);
+	// every partial product is a binomial coefficient, so dividing by gcd first keeps the intermediate small
+	(0..k).fold(ZZ::one(), |acc, i| {
+		let g = crate::algebra::num::gcd(*acc, i + 1);
+		ZZ::from(*acc / g) * ZZ::from((n - i) / ((i + 1) / g))
+	})
+}
+
+/// `(k_1 + ... + k_m)! / (k_1! ... k_m!)`
+pub fn multinomial(ks : &[usize]) -> ZZ {
+	let mut total = 0;
+	ks.iter().fold(ZZ::one(), |acc, k| {
+		total += *k;
+		acc * binomial(ZZ::from(total as i128), ZZ::from(*k as i128))
+	})
+}
+
+/// `a * b mod m` without overflow
+fn mul_mod(a : u128, b : u128, m : u128) -> u128 {
+	if m < 1 << 64 {
+		return (a % m) * (b % m) % m;
+	}
+	let add_mod = |x : u128, y : u128| if x >= m - y { x - (m - y) } else { x + y };
+	let (mut a, mut b, mut result) = (a % m, b % m, 0);
+	while b > 0 {
+		if b & 1 == 1 {
+			result = add_mod(result, a);
+		}
+		a = add_mod(a, a);
+		b >>= 1;
+	}
+	result
+}
+
+fn pow_mod(mut base : u128, mut exp : u128, m : u128) -> u128 {
+	let mut result = 1 % m;
+	base %= m;
+	while exp > 0 {
+		if exp & 1 == 1 {
+			result = mul_mod(result, base, m);
+		}
+		base = mul_mod(base, base, m);
+		exp >>= 1;
+	}
+	result
+}
+
+const SMALL_PRIMES : [u128; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Miller–Rabin with the first 13 primes as bases,
+/// which is deterministic below 3.3 * 10^24 and a strong probable prime test above
+fn is_prime_u128(n : u128) -> bool {
+	if n < 2 {
+		return false;
+	}
+	for p in SMALL_PRIMES {
+		if n.is_multiple_of(p) {
+			return n == p;
+		}
+	}
+	let s = (n - 1).trailing_zeros();
+	let d = (n - 1) >> s;
+	SMALL_PRIMES.iter().all(|a| {
+		let mut x = pow_mod(*a, d, n);
+		if x == 1 || x == n - 1 {
+			return true;
+		}
+		(1..s).any(|_| {
+			x = mul_mod(x, x, n);
+			x == n - 1
+		})
+	})
+}
+
+/// whether `|n|` is a prime
+pub fn is_prime(n : ZZ) -> bool {
+	is_prime_u128(n.unsigned_abs())
+}
+
+fn gcd_u128(mut a : u128, mut b : u128) -> u128 {
+	while b != 0 {
+		(a, b) = (b, a % b);
+	}
+	a
+}
+
+/// a non-trivial divisor of the odd composite `n`, by Pollard's rho with Brent's cycle detection
+fn pollard_rho(n : u128) -> u128 {
+	for c in 1.. {
+		let f = |x : u128| (mul_mod(x, x, n) + c) % n;
+		let (mut x, mut y, mut q, mut g) = (2, 2, 1, 1);
+		let mut ys = 2;
+		let mut r = 1;
+		while g == 1 {
+			x = y;
+			for _ in 0..r {
+				y = f(y);
+			}
+			let mut k = 0;
+			while k < r && g == 1 {
+				ys = y;
+				// batch the gcds over up to 128 steps
+				for _ in 0..128.min(r - k) {
+					y = f(y);
+					q = mul_mod(q, x.abs_diff(y), n);
+				}
+				g = gcd_u128(q, n);
+				k += 128;
+			}
+			r *= 2;
+		}
+		if g == n {
+			// the batch overshot, redo it one step at a time
+			loop {
+				ys = f(ys);
+				g = gcd_u128(x.abs_diff(ys), n);
+				if g > 1 {
+					break;
+				}
+			}
+		}
+		if g != n {
+			return g;
+		}
+	}
+	unreachable!()
+}
+
+fn factor_into(n : u128, primes : &mut Vec<u128>) {
+	if n == 1 {
+		return;
+	}
+	if is_prime_u128(n) {
+		primes.push(n);
+		return;
+	}
+	let d = pollard_rho(n);
+	factor_into(d, primes);
+	factor_into(n / d, primes);
+}
+
+/// the prime factorization of `|n|` as `(p, e)` with increasing `p`, empty for `n = ±1`
+pub fn factor(n : ZZ) -> Vec<(ZZ, usize)> {
+	if n.is_zero() {
+		panic!("0 has no factorization")
+	}
+	let mut n = n.unsigned_abs();
+	let mut primes = Vec::new();
+	// trial division first, Pollard's rho is only worth it for the large factors
+	// a composite never divides what is left once its prime factors are removed
+	for p in 2..1000 {
+		while n.is_multiple_of(p) {
+			primes.push(p);
+			n /= p;
+		}
+	}
+	factor_into(n, &mut primes);
+	primes.sort();
+	let mut result : Vec<(ZZ, usize)> = Vec::new();
+	for p in primes {
+		match result.last_mut() {
+			Some((q, e)) if q.unsigned_abs() == p => *e += 1,
+			_ => result.push((ZZ::from(p as i128), 1)),
+		}
+	}
+	result
+}
+
+/// the number of `1 <= k <= n` coprime to `n`, where `n > 0`
+pub fn euler_phi(n : ZZ) -> ZZ {
+	factor(n).into_iter().fold(ZZ::one(), |acc, (p, e)| acc * (p - ZZ::one()) * p.pow(e - 1))
+}
+
+/// `(-1)^k` if `n > 0` is a product of `k` distinct primes, zero otherwise
+pub fn moebius(n : ZZ) -> ZZ {
+	let factors = factor(n);
+	if factors.iter().any(|(_, e)| *e > 1) {
+		ZZ::zero()
+	} else if factors.len().is_multiple_of(2) {
+		ZZ::one()
+	} else {
+		-ZZ::one()
+	}
+}
+
+/// the positive divisors of `n != 0`, increasing
+pub fn divisors(n : ZZ) -> Vec<ZZ> {
+	let mut result = vec![ZZ::one()];
+	for (p, e) in factor(n) {
+		let previous = result.clone();
+		let mut power = ZZ::one();
+		for _ in 0..e {
+			power = power * p;
+			result.extend(previous.iter().map(|d| *d * power));
+		}
+	}
+	result.sort_by_key(|d| **d);
+	result
+}
+
+/// tables of arithmetic functions on `0..=limit`, by a linear sieve
+#[derive(Debug, Clone)]
+pub struct Sieve {
+	least_factor : Vec<usize>,
+	primes : Vec<usize>,
+	phi : Vec<usize>,
+	mu : Vec<i8>,
+}
+impl Sieve {
+	pub fn new(limit : usize) -> Sieve {
+		let mut least_factor = vec![0; limit + 1];
+		let mut phi = vec![0; limit + 1];
+		let mut mu = vec![0; limit + 1];
+		let mut primes = Vec::new();
+		if limit >= 1 {
+			phi[1] = 1;
+			mu[1] = 1;
+		}
+		for i in 2..=limit {
+			if least_factor[i] == 0 {
+				least_factor[i] = i;
+				phi[i] = i - 1;
+				mu[i] = -1;
+				primes.push(i);
+			}
+			// every composite is crossed out once, by its least prime factor
+			for p in primes.iter().copied() {
+				if p > least_factor[i] || i * p > limit {
+					break;
+				}
+				least_factor[i * p] = p;
+				if p == least_factor[i] {
+					phi[i * p] = phi[i] * p;
+					mu[i * p] = 0;
+				} else {
+					phi[i * p] = phi[i] * (p - 1);
+					mu[i * p] = -mu[i];
+				}
+			}
+		}
+		Sieve { least_factor, primes, phi, mu }
+	}
+
+	pub fn limit(&self) -> usize {
+		self.least_factor.len() - 1
+	}
+	/// the primes up to the limit, increasing
+	pub fn primes(&self) -> &[usize] {
+		&self.primes
+	}
+	pub fn is_prime(&self, n : usize) -> bool {
+		n >= 2 && self.least_factor[n] == n
+	}
+	/// the least prime factor of `n >= 2`
+	pub fn least_factor(&self, n : usize) -> usize {
+		self.least_factor[n]
+	}
+	/// the factorization of `1 <= n <= limit` as in `factor`, without any division by trial
+	pub fn factor(&self, mut n : usize) -> Vec<(usize, usize)> {
+		let mut result : Vec<(usize, usize)> = Vec::new();
+		while n > 1 {
+			let p = self.least_factor[n];
+			match result.last_mut() {
+				Some((q, e)) if *q == p => *e += 1,
+				_ => result.push((p, 1)),
+			}
+			n /= p;
+		}
+		result
+	}
+	pub fn euler_phi(&self, n : usize) -> usize {
+		self.phi[n]
+	}
+	pub fn moebius(&self, n : usize) -> i8 {
+		self.mu[n]
+	}
+}
+
+#[test] fn combinatorial_numbers() {
+	let zz = ZZ::from;
+	assert_eq!(factorial(0), zz(1));
+	assert_eq!(factorial(20), zz(2432902008176640000));
+	assert_eq!(binomial(zz(10), zz(3)), zz(120));
+	assert_eq!(binomial(zz(3), zz(5)), zz(0));
+	assert_eq!(binomial(zz(-2), zz(3)), zz(-4));
+	// far beyond where 100! fits, the intermediate values stay binomials
+	assert_eq!(binomial(zz(100), zz(50)), zz(100891344545564193334812497256));
+	assert_eq!(multinomial(&[2, 1, 1]), zz(12));
+	assert_eq!(multinomial(&[]), zz(1));
+	assert_eq!(lcm(zz(4), zz(-6)), zz(12));
+}
+
+#[test] fn factorization() {
+	let zz = ZZ::from;
+	assert!(is_prime(zz(2)) && is_prime(zz(-7)) && !is_prime(zz(1)) && !is_prime(zz(561)));
+	assert!(is_prime(zz((1 << 61) - 1)));
+	assert!(is_prime(zz((1 << 89) - 1)));
+	assert_eq!(factor(zz(-360)), vec![(zz(2), 3), (zz(3), 2), (zz(5), 1)]);
+	// two primes with 10 digits
+	let (p, q) = (1000000007, 2147483647);
+	assert_eq!(factor(zz(p * q)), vec![(zz(p), 1), (zz(q), 1)]);
+	assert_eq!(factor(zz((1 << 64) + 1)), vec![(zz(274177), 1), (zz(67280421310721), 1)]);
+
+	assert_eq!(euler_phi(zz(36)), zz(12));
+	assert_eq!(moebius(zz(30)), zz(-1));
+	assert_eq!(moebius(zz(12)), zz(0));
+	assert_eq!(divisors(zz(12)), [1, 2, 3, 4, 6, 12].map(zz).to_vec());
+}
+
+#[test] fn sieve_tables() {
+	let sieve = Sieve::new(1000);
+	assert_eq!(sieve.primes().len(), 168);
+	for n in 1..=1000 {
+		let z = ZZ::from(n as i128);
+		assert_eq!(sieve.euler_phi(n) as i128, *euler_phi(z));
+		assert_eq!(sieve.moebius(n) as i128, *moebius(z));
+		assert_eq!(sieve.factor(n), factor(z).into_iter().map(|(p, e)| (*p as usize, e)).collect::<Vec<_>>());
+		assert_eq!(sieve.is_prime(n), is_prime(z));
+	}
+	// sum of phi over the divisors
+	assert_eq!(divisors(ZZ::from(840)).iter().map(|d| sieve.euler_phi(**d as usize)).sum::<usize>(), 840);
+}
@@ -0,0 +1,417 @@
+//! finite partially ordered sets
+
+use std::collections::VecDeque;
+
+use cell_complex::SimplicialComplex;
+
+use crate::{MathClass, InvariantError};
+use crate::algebra::{AddGroup, Ring};
+use crate::algebra::num::ZZ;
+
+/// a finite poset on `elements`, where the order is kept as the matrix of `<=` on the indices
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poset<T> {
+	elements : Vec<T>,
+	le : Vec<Vec<bool>>,
+}
+impl<T> MathClass for Poset<T> {
+	type Error = InvariantError;
+	fn check(&self) -> Result<(), InvariantError> {
+		let n = self.elements.len();
+		for i in 0..n {
+			if !self.le[i][i] {
+				return Err(format!("poset: not reflexive at {}", i).into());
+			}
+			for j in 0..n {
+				if i != j && self.le[i][j] && self.le[j][i] {
+					return Err(format!("poset: not antisymmetric at {} and {}", i, j).into());
+				}
+				for k in 0..n {
+					if self.le[i][j] && self.le[j][k] && !self.le[i][k] {
+						return Err(format!("poset: not transitive at {}, {} and {}", i, j, k).into());
+					}
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<T> Poset<T> {
+	/// the order given by `le`, panics if it is not a partial order
+	pub fn new(elements : Vec<T>, le : impl Fn(&T, &T) -> bool) -> Poset<T> {
+		match Poset::try_new(elements, le) {
+			Ok(poset) => poset,
+			Err(e) => panic!("Poset: {}", e),
+		}
+	}
+
+	/// the order given by `le`, if it is a partial order
+	pub fn try_new(elements : Vec<T>, le : impl Fn(&T, &T) -> bool) -> Result<Poset<T>, InvariantError> {
+		let le = elements.iter().map(|a| elements.iter().map(|b| le(a, b)).collect()).collect();
+		let poset = Poset { elements, le };
+		poset.check()?;
+		Ok(poset)
+	}
+
+	/// the order generated by the relations `elements[i] <= elements[j]` for `(i, j)` in `relations`
+	pub fn from_relations(elements : Vec<T>, relations : &[(usize, usize)]) -> Poset<T> {
+		let n = elements.len();
+		let mut le = vec![vec![false; n]; n];
+		for (i, row) in le.iter_mut().enumerate() {
+			row[i] = true;
+		}
+		for (i, j) in relations {
+			le[*i][*j] = true;
+		}
+		// Warshall's transitive closure
+		for k in 0..n {
+			let through = le[k].clone();
+			for row in le.iter_mut() {
+				if row[k] {
+					for (entry, t) in row.iter_mut().zip(through.iter()) {
+						*entry |= *t;
+					}
+				}
+			}
+		}
+		let poset = Poset { elements, le };
+		if let Err(s) = poset.check() {
+			panic!("Poset: {}", s);
+		}
+		poset
+	}
+
+	pub fn len(&self) -> usize {
+		self.elements.len()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.elements.is_empty()
+	}
+	pub fn elements(&self) -> &[T] {
+		&self.elements
+	}
+	pub fn index_of(&self, x : &T) -> Option<usize> where T : PartialEq {
+		self.elements.iter().position(|y| y == x)
+	}
+
+	pub fn le(&self, i : usize, j : usize) -> bool {
+		self.le[i][j]
+	}
+	pub fn lt(&self, i : usize, j : usize) -> bool {
+		i != j && self.le[i][j]
+	}
+	pub fn is_comparable(&self, i : usize, j : usize) -> bool {
+		self.le[i][j] || self.le[j][i]
+	}
+
+	/// the pairs `(i, j)` where `j` covers `i`, the edges of the Hasse diagram
+	pub fn covers(&self) -> Vec<(usize, usize)> {
+		let n = self.len();
+		let mut result = Vec::new();
+		for i in 0..n {
+			for j in 0..n {
+				if self.lt(i, j) && !(0..n).any(|k| self.lt(i, k) && self.lt(k, j)) {
+					result.push((i, j));
+				}
+			}
+		}
+		result
+	}
+
+	pub fn minimal_elements(&self) -> Vec<usize> {
+		(0..self.len()).filter(|j| !(0..self.len()).any(|i| self.lt(i, *j))).collect()
+	}
+	pub fn maximal_elements(&self) -> Vec<usize> {
+		(0..self.len()).filter(|i| !(0..self.len()).any(|j| self.lt(*i, j))).collect()
+	}
+
+	/// the subposet on `indices`, in that order
+	pub fn subposet(&self, indices : &[usize]) -> Poset<T> where T : Clone {
+		Poset {
+			elements : indices.iter().map(|i| self.elements[*i].clone()).collect(),
+			le : indices.iter().map(|i| indices.iter().map(|j| self.le[*i][*j]).collect()).collect(),
+		}
+	}
+
+	/// the interval `[i, j]`
+	pub fn interval(&self, i : usize, j : usize) -> Poset<T> where T : Clone {
+		let indices : Vec<usize> = (0..self.len()).filter(|k| self.le(i, *k) && self.le(*k, j)).collect();
+		self.subposet(&indices)
+	}
+
+	pub fn is_chain(&self, indices : &[usize]) -> bool {
+		indices.iter().enumerate().all(|(a, i)| indices[a + 1..].iter().all(|j| self.is_comparable(*i, *j)))
+	}
+	pub fn is_antichain(&self, indices : &[usize]) -> bool {
+		indices.iter().enumerate().all(|(a, i)| indices[a + 1..].iter().all(|j| !self.is_comparable(*i, *j) && i != j))
+	}
+
+	/// all linear extensions, as the indices from the least to the greatest
+	pub fn linear_extensions(&self) -> Vec<Vec<usize>> {
+		fn extend<T>(poset : &Poset<T>, placed : &mut Vec<bool>, current : &mut Vec<usize>, result : &mut Vec<Vec<usize>>) {
+			if current.len() == poset.len() {
+				result.push(current.clone());
+				return;
+			}
+			for j in 0..poset.len() {
+				if !placed[j] && (0..poset.len()).all(|i| placed[i] || !poset.lt(i, j)) {
+					placed[j] = true;
+					current.push(j);
+					extend(poset, placed, current, result);
+					current.pop();
+					placed[j] = false;
+				}
+			}
+		}
+		let mut result = Vec::new();
+		extend(self, &mut vec![false; self.len()], &mut Vec::new(), &mut result);
+		result
+	}
+
+	/// all non-empty chains, each increasing
+	pub fn chains(&self) -> Vec<Vec<usize>> {
+		let extension = self.linear_extension();
+		let mut result : Vec<Vec<usize>> = Vec::new();
+		// extend chains only upwards along a linear extension, so that every chain appears once
+		let mut queue : VecDeque<Vec<usize>> = extension.iter().map(|i| vec![*i]).collect();
+		let position : Vec<usize> = {
+			let mut p = vec![0; self.len()];
+			for (k, i) in extension.iter().enumerate() {
+				p[*i] = k;
+			}
+			p
+		};
+		while let Some(chain) = queue.pop_front() {
+			let top = *chain.last().unwrap();
+			for j in extension[position[top] + 1..].iter() {
+				if self.lt(top, *j) {
+					let mut longer = chain.clone();
+					longer.push(*j);
+					queue.push_back(longer);
+				}
+			}
+			result.push(chain);
+		}
+		result
+	}
+
+	pub fn maximal_chains(&self) -> Vec<Vec<usize>> {
+		let covers = self.covers();
+		let mut result = Vec::new();
+		let mut stack : Vec<Vec<usize>> = self.minimal_elements().into_iter().map(|i| vec![i]).collect();
+		while let Some(chain) = stack.pop() {
+			let top = *chain.last().unwrap();
+			let ups : Vec<usize> = covers.iter().filter(|(i, _)| *i == top).map(|(_, j)| *j).collect();
+			for j in ups.iter() {
+				let mut longer = chain.clone();
+				longer.push(*j);
+				stack.push(longer);
+			}
+			if ups.is_empty() {
+				result.push(chain);
+			}
+		}
+		result.sort();
+		result
+	}
+
+	/// all non-empty antichains, each increasing as indices
+	pub fn antichains(&self) -> Vec<Vec<usize>> {
+		fn extend<T>(poset : &Poset<T>, start : usize, current : &mut Vec<usize>, result : &mut Vec<Vec<usize>>) {
+			for j in start..poset.len() {
+				if current.iter().all(|i| !poset.is_comparable(*i, j)) {
+					current.push(j);
+					result.push(current.clone());
+					extend(poset, j + 1, current, result);
+					current.pop();
+				}
+			}
+		}
+		let mut result = Vec::new();
+		extend(self, 0, &mut Vec::new(), &mut result);
+		result
+	}
+
+	/// `μ(i, j)`, by `μ(i, i) = 1` and `Σ_{i <= k <= j} μ(i, k) = 0` for `i < j`
+	pub fn moebius(&self, i : usize, j : usize) -> ZZ {
+		if !self.le(i, j) {
+			return ZZ::zero();
+		}
+		let extension = self.linear_extension();
+		let mut mu = vec![ZZ::zero(); self.len()];
+		for k in extension {
+			if self.le(i, k) && self.le(k, j) {
+				mu[k] = if k == i {
+					ZZ::one()
+				} else {
+					-(0..self.len()).filter(|l| self.le(i, *l) && self.lt(*l, k)).fold(ZZ::zero(), |acc, l| acc + mu[l])
+				};
+			}
+		}
+		mu[j]
+	}
+
+	/// one linear extension, by Kahn's algorithm
+	fn linear_extension(&self) -> Vec<usize> {
+		let n = self.len();
+		let mut below : Vec<usize> = (0..n).map(|j| (0..n).filter(|i| self.lt(*i, j)).count()).collect();
+		let mut queue : VecDeque<usize> = (0..n).filter(|j| below[*j] == 0).collect();
+		let mut result = Vec::with_capacity(n);
+		while let Some(i) = queue.pop_front() {
+			result.push(i);
+			for (j, count) in below.iter_mut().enumerate() {
+				if self.lt(i, j) {
+					*count -= 1;
+					if *count == 0 {
+						queue.push_back(j);
+					}
+				}
+			}
+		}
+		result
+	}
+
+	/// the simplicial complex of the chains, on the indices as vertices
+	pub fn order_complex(&self) -> SimplicialComplex {
+		SimplicialComplex::from(self.len(), self.maximal_chains())
+	}
+
+	/// the Greene–Kleitman partition `λ`, where `λ_1 + ... + λ_k` is the largest size of a union of `k` chains;
+	/// its conjugate does the same for antichains
+	///
+	/// by a minimum cost flow: each unit of flow is a chain, and each element carries one unit at cost `-1`
+	pub fn greene_kleitman(&self) -> Vec<usize> {
+		let n = self.len();
+		// the nodes: 2i is the entry of element i, 2i + 1 its exit, then the source and the sink
+		let (source, sink) = (2 * n, 2 * n + 1);
+		let mut flow = MinCostFlow::new(2 * n + 2);
+		for i in 0..n {
+			flow.add_edge(source, 2 * i, 1, 0);
+			flow.add_edge(2 * i, 2 * i + 1, 1, -1);
+			flow.add_edge(2 * i + 1, sink, 1, 0);
+			for j in 0..n {
+				if self.lt(i, j) {
+					flow.add_edge(2 * i + 1, 2 * j, n as i64, 0);
+				}
+			}
+		}
+		let mut lambda = Vec::new();
+		let mut covered = 0;
+		while covered < n {
+			let cost = flow.augment(source, sink).expect("each element alone is a chain");
+			let part = (-cost) as usize;
+			lambda.push(part);
+			covered += part;
+		}
+		lambda
+	}
+}
+
+impl<T : PartialOrd> Poset<T> {
+	/// the poset of `elements` ordered by `<=`
+	pub fn from_partial_ord(elements : Vec<T>) -> Poset<T> {
+		Poset::new(elements, |a, b| a <= b)
+	}
+}
+
+/// the successive shortest paths by Bellman–Ford, as the costs may be negative
+struct MinCostFlow {
+	/// `(to, capacity, cost)`, where the reverse of edge `e` is `e ^ 1`
+	edges : Vec<(usize, i64, i64)>,
+	adjacent : Vec<Vec<usize>>,
+}
+impl MinCostFlow {
+	fn new(nodes : usize) -> MinCostFlow {
+		MinCostFlow { edges : Vec::new(), adjacent : vec![Vec::new(); nodes] }
+	}
+
+	fn add_edge(&mut self, from : usize, to : usize, capacity : i64, cost : i64) {
+		self.adjacent[from].push(self.edges.len());
+		self.edges.push((to, capacity, cost));
+		self.adjacent[to].push(self.edges.len());
+		self.edges.push((from, 0, -cost));
+	}
+
+	/// push one more unit along a cheapest path, returning its cost
+	fn augment(&mut self, source : usize, sink : usize) -> Option<i64> {
+		let nodes = self.adjacent.len();
+		let mut distance : Vec<Option<i64>> = vec![None; nodes];
+		let mut through : Vec<Option<usize>> = vec![None; nodes];
+		distance[source] = Some(0);
+		for _ in 0..nodes {
+			let mut changed = false;
+			for u in 0..nodes {
+				let Some(d) = distance[u] else { continue };
+				for e in self.adjacent[u].iter() {
+					let (v, capacity, cost) = self.edges[*e];
+					if capacity > 0 && distance[v].is_none_or(|dv| d + cost < dv) {
+						distance[v] = Some(d + cost);
+						through[v] = Some(*e);
+						changed = true;
+					}
+				}
+			}
+			if !changed {
+				break;
+			}
+		}
+		let cost = distance[sink]?;
+		let mut v = sink;
+		while let Some(e) = through[v] {
+			self.edges[e].1 -= 1;
+			self.edges[e ^ 1].1 += 1;
+			v = self.edges[e ^ 1].0;
+		}
+		Some(cost)
+	}
+}
+
+#[test] fn poset_basics() {
+	// the divisors of 12
+	let divisors = Poset::new(vec![1, 2, 3, 4, 6, 12], |a, b| b % a == 0);
+	assert_eq!(divisors.covers(), vec![(0, 1), (0, 2), (1, 3), (1, 4), (2, 4), (3, 5), (4, 5)]);
+	assert_eq!(divisors.minimal_elements(), vec![0]);
+	assert_eq!(divisors.maximal_elements(), vec![5]);
+	assert_eq!(divisors.linear_extensions().len(), 5);
+	assert_eq!(divisors.maximal_chains().len(), 3);
+	assert!(divisors.is_chain(&[0, 1, 3, 5]) && !divisors.is_chain(&[1, 2]));
+	assert!(divisors.is_antichain(&[2, 3]));
+	assert_eq!(divisors.antichains().len(), 6 + 3);
+	// the classical Möbius function
+	for (j, d) in [1, 2, 3, 4, 6, 12].into_iter().enumerate() {
+		assert_eq!(divisors.moebius(0, j), crate::number_theory::moebius(ZZ::from(d)));
+	}
+	assert_eq!(divisors.interval(1, 5).elements(), &[2, 4, 6, 12]);
+
+	let chain = Poset::from_relations(vec!['a', 'b', 'c'], &[(0, 1), (1, 2)]);
+	assert!(chain.le(0, 2));
+	assert_eq!(chain.chains().len(), 7);
+	assert!(std::panic::catch_unwind(|| Poset::from_relations(vec![0, 1], &[(0, 1), (1, 0)])).is_err());
+}
+
+#[test] fn order_complex() {
+	use cell_complex::Complex;
+	// the boolean lattice of {0, 1, 2} without its bottom and top
+	let subsets : Vec<usize> = (1..7).collect();
+	let proper = Poset::new(subsets, |a, b| a & b == *a);
+	let complex = proper.order_complex();
+	// the barycentric subdivision of the boundary of a triangle, a circle
+	assert_eq!(complex.f_vector(), vec![6, 6]);
+	assert_eq!(complex.euler(), 0);
+	// Philip Hall: μ(0, 1) is the reduced Euler characteristic of the open interval
+	let boolean = Poset::new((0..8).collect::<Vec<usize>>(), |a, b| a & b == *a);
+	assert_eq!(*boolean.moebius(0, 7), complex.reduced_euler() as i128);
+	assert_eq!(proper.chains().len(), 12);
+}
+
+#[test] fn greene_kleitman() {
+	let divisors = Poset::new(vec![1, 2, 3, 4, 6, 12], |a, b| b % a == 0);
+	assert_eq!(divisors.greene_kleitman(), vec![4, 2]);
+	// an antichain and a chain
+	assert_eq!(Poset::new(vec![0, 1, 2], |a, b| a == b).greene_kleitman(), vec![1, 1, 1]);
+	assert_eq!(Poset::from_partial_ord(vec![0, 1, 2]).greene_kleitman(), vec![3]);
+	// the width is 3, from the antichain {2, 4, 5}
+	let zigzag = Poset::from_relations((0..6).collect(), &[(0, 1), (1, 2), (3, 1), (1, 4), (3, 5)]);
+	assert_eq!(zigzag.greene_kleitman(), vec![3, 2, 1]);
+}
@@ -0,0 +1,402 @@
+//! root systems of the simple Lie algebras, their Weyl groups and the Weyl dimension formula
+//!
+//! the simple roots are numbered as in Bourbaki, and the Cartan matrix is `a_ij = <α_i^∨, α_j>`,
+//! so that the simple root `α_j` is `Σ_i a_ij ω_i` in the fundamental weights;
+//! roots are written in the simple roots and weights in the fundamental weights
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::algebra::{AddGroup, Ring, Field};
+use crate::algebra::num::{ZZ, QQ};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CartanType {
+	A(usize),
+	B(usize),
+	C(usize),
+	D(usize),
+	E(usize),
+	F4,
+	G2,
+}
+impl CartanType {
+	pub fn rank(&self) -> usize {
+		match self {
+			CartanType::A(n) | CartanType::B(n) | CartanType::C(n) | CartanType::D(n) | CartanType::E(n) => *n,
+			CartanType::F4 => 4,
+			CartanType::G2 => 2,
+		}
+	}
+
+	/// the Cartan matrix, panics for the types that do not exist, e.g. `B(1)` or `E(5)`
+	pub fn cartan_matrix(&self) -> Vec<Vec<i64>> {
+		let n = self.rank();
+		let mut a = vec![vec![0; n]; n];
+		let mut link = |i : usize, j : usize| {
+			a[i][j] = -1;
+			a[j][i] = -1;
+		};
+		match self {
+			CartanType::A(n) if *n >= 1 => (1..*n).for_each(|i| link(i - 1, i)),
+			CartanType::B(n) | CartanType::C(n) if *n >= 2 => (1..*n).for_each(|i| link(i - 1, i)),
+			CartanType::D(n) if *n >= 4 => {
+				(1..*n - 1).for_each(|i| link(i - 1, i));
+				link(n - 3, n - 1);
+			},
+			CartanType::E(n) if (6..=8).contains(n) => {
+				link(0, 2);
+				link(1, 3);
+				(3..*n).for_each(|i| link(i - 1, i));
+			},
+			CartanType::F4 => (1..4).for_each(|i| link(i - 1, i)),
+			CartanType::G2 => link(0, 1),
+			_ => panic!("there is no root system of type {:?}", self),
+		}
+		for (i, row) in a.iter_mut().enumerate() {
+			row[i] = 2;
+		}
+		// the arrows point to the short roots
+		match self {
+			CartanType::B(n) => a[n - 1][n - 2] = -2,
+			CartanType::C(n) => a[n - 2][n - 1] = -2,
+			CartanType::F4 => a[2][1] = -2,
+			CartanType::G2 => a[0][1] = -3,
+			_ => {},
+		}
+		a
+	}
+}
+
+/// an element of the Weyl group as its reduced word `s_{i_1} ... s_{i_k}`,
+/// the word is the least one in the sense of `RootSystem::element`, so equal elements have equal words
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WeylElement {
+	word : Vec<usize>,
+}
+impl WeylElement {
+	pub fn word(&self) -> &[usize] {
+		&self.word
+	}
+	pub fn length(&self) -> usize {
+		self.word.len()
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RootSystem {
+	cartan_type : CartanType,
+	cartan : Vec<Vec<i64>>,
+	/// `|α_i|^2 / 2`, coprime integers
+	lengths : Vec<i64>,
+	positive_roots : Vec<Vec<i64>>,
+}
+
+impl RootSystem {
+	pub fn new(cartan_type : CartanType) -> RootSystem {
+		let cartan = cartan_type.cartan_matrix();
+		let n = cartan.len();
+		// symmetrize: ε_i a_ij = ε_j a_ji along the (connected) Dynkin diagram
+		let mut lengths = vec![0; n];
+		lengths[0] = 6;
+		let mut queue = VecDeque::from([0]);
+		while let Some(i) = queue.pop_front() {
+			for j in 0..n {
+				if cartan[i][j] != 0 && lengths[j] == 0 {
+					lengths[j] = lengths[i] * cartan[i][j] / cartan[j][i];
+					queue.push_back(j);
+				}
+			}
+		}
+		let g = lengths.iter().fold(0, |g, l| crate::algebra::num::gcd(g as i128, *l as i128) as i64);
+		let lengths = lengths.into_iter().map(|l| l / g).collect();
+		let mut system = RootSystem { cartan_type, cartan, lengths, positive_roots : Vec::new() };
+		system.positive_roots = system.generate_positive_roots();
+		system
+	}
+
+	pub fn cartan_type(&self) -> CartanType {
+		self.cartan_type
+	}
+	pub fn rank(&self) -> usize {
+		self.cartan.len()
+	}
+	pub fn cartan_matrix(&self) -> &[Vec<i64>] {
+		&self.cartan
+	}
+
+	/// `<α_i^∨, β>` for a root `β` in the simple roots
+	fn pairing(&self, i : usize, root : &[i64]) -> i64 {
+		root.iter().zip(self.cartan[i].iter()).map(|(c, a)| c * a).sum()
+	}
+
+	/// by the root strings: `β + α_i` is a root iff `q = p - <α_i^∨, β> > 0`,
+	/// where `β - p α_i, ..., β` are roots, level by level in the height
+	fn generate_positive_roots(&self) -> Vec<Vec<i64>> {
+		let n = self.rank();
+		let mut roots : Vec<Vec<i64>> = self.simple_roots();
+		let mut known : HashSet<Vec<i64>> = roots.iter().cloned().collect();
+		let mut level = roots.clone();
+		while !level.is_empty() {
+			let mut next = Vec::new();
+			for beta in level.iter() {
+				for i in 0..n {
+					let mut p = 0;
+					let mut down = beta.clone();
+					loop {
+						down[i] -= 1;
+						if !known.contains(&down) {
+							break;
+						}
+						p += 1;
+					}
+					if p - self.pairing(i, beta) > 0 {
+						let mut up = beta.clone();
+						up[i] += 1;
+						if known.insert(up.clone()) {
+							next.push(up);
+						}
+					}
+				}
+			}
+			roots.extend(next.iter().cloned());
+			level = next;
+		}
+		roots
+	}
+
+	/// the standard basis, as roots are written in the simple roots
+	pub fn simple_roots(&self) -> Vec<Vec<i64>> {
+		(0..self.rank()).map(|i| (0..self.rank()).map(|j| if i == j { 1 } else { 0 }).collect()).collect()
+	}
+
+	/// the positive roots by increasing height, the simple roots first
+	pub fn positive_roots(&self) -> &[Vec<i64>] {
+		&self.positive_roots
+	}
+
+	/// the highest root
+	pub fn highest_root(&self) -> &[i64] {
+		self.positive_roots.last().expect("a root system of positive rank")
+	}
+
+	/// a root, written in the simple roots, as a weight
+	pub fn root_to_weight(&self, root : &[i64]) -> Vec<i64> {
+		(0..self.rank()).map(|i| self.pairing(i, root)).collect()
+	}
+
+	/// the fundamental weights in the simple roots, the columns of the inverse Cartan matrix
+	pub fn fundamental_weights(&self) -> Vec<Vec<QQ>> {
+		let n = self.rank();
+		let qq = |x : i64| QQ::from_pair(x as i128, 1);
+		// Gauss–Jordan on [A | I]
+		let mut m : Vec<Vec<QQ>> = (0..n).map(|i| {
+			self.cartan[i].iter().map(|a| qq(*a)).chain((0..n).map(|j| qq((i == j) as i64))).collect()
+		}).collect();
+		for c in 0..n {
+			let p = (c..n).find(|r| !m[*r][c].is_zero()).expect("a Cartan matrix is invertible");
+			m.swap(c, p);
+			let inv = m[c][c].inv();
+			m[c] = m[c].iter().map(|x| *x * inv).collect();
+			for r in 0..n {
+				if r != c && !m[r][c].is_zero() {
+					let f = m[r][c];
+					m[r] = m[r].iter().zip(m[c].iter()).map(|(x, y)| *x - f * *y).collect();
+				}
+			}
+		}
+		(0..n).map(|i| (0..n).map(|j| m[j][n + i]).collect()).collect()
+	}
+
+	/// `ρ`, the sum of the fundamental weights
+	pub fn rho(&self) -> Vec<i64> {
+		vec![1; self.rank()]
+	}
+
+	pub fn is_dominant(&self, weight : &[i64]) -> bool {
+		weight.iter().all(|x| *x >= 0)
+	}
+
+	/// `(α, α) / 2` in the normalization where the shortest simple root has `(α, α) / 2 = 1`
+	fn half_norm(&self, root : &[i64]) -> i64 {
+		let mut sum = 0;
+		for i in 0..self.rank() {
+			sum += root[i] * self.lengths[i] * self.pairing(i, root);
+		}
+		sum / 2
+	}
+
+	/// `<λ, α^∨>` for a weight `λ` and a root `α`
+	pub fn coroot_pairing(&self, weight : &[i64], root : &[i64]) -> i64 {
+		let sum : i64 = (0..self.rank()).map(|i| root[i] * self.lengths[i] * weight[i]).sum();
+		sum / self.half_norm(root)
+	}
+
+	/// `s_i(λ) = λ - <λ, α_i^∨> α_i`
+	pub fn simple_reflection(&self, i : usize, weight : &[i64]) -> Vec<i64> {
+		let k = weight[i];
+		weight.iter().enumerate().map(|(j, x)| x - k * self.cartan[j][i]).collect()
+	}
+
+	/// `s_α(λ) = λ - <λ, α^∨> α`
+	pub fn reflect(&self, root : &[i64], weight : &[i64]) -> Vec<i64> {
+		let k = self.coroot_pairing(weight, root);
+		let alpha = self.root_to_weight(root);
+		weight.iter().zip(alpha.iter()).map(|(x, a)| x - k * a).collect()
+	}
+
+	/// `w(λ)` for the word `w = s_{i_1} ... s_{i_k}`, which need not be reduced
+	pub fn act(&self, word : &[usize], weight : &[i64]) -> Vec<i64> {
+		word.iter().rev().fold(weight.to_vec(), |acc, i| self.simple_reflection(*i, &acc))
+	}
+
+	/// the element of the Weyl group of any word, by its action on `ρ`, which is free:
+	/// taking the least `i` with `<μ, α_i^∨> < 0` until `μ = w(ρ)` becomes dominant gives a reduced word
+	pub fn element(&self, word : &[usize]) -> WeylElement {
+		self.element_at(self.act(word, &self.rho()))
+	}
+
+	/// the element `w` with `w(ρ) = mu`
+	fn element_at(&self, mut mu : Vec<i64>) -> WeylElement {
+		let mut word = Vec::new();
+		while let Some(i) = mu.iter().position(|x| *x < 0) {
+			mu = self.simple_reflection(i, &mu);
+			word.push(i);
+		}
+		WeylElement { word }
+	}
+
+	pub fn identity(&self) -> WeylElement {
+		WeylElement { word : Vec::new() }
+	}
+
+	pub fn multiply(&self, lhs : &WeylElement, rhs : &WeylElement) -> WeylElement {
+		self.element(&[lhs.word.as_slice(), rhs.word.as_slice()].concat())
+	}
+
+	pub fn inverse(&self, w : &WeylElement) -> WeylElement {
+		self.element(&w.word.iter().rev().copied().collect::<Vec<_>>())
+	}
+
+	/// the reflection `s_α` of a positive root `α`
+	pub fn reflection(&self, root : &[i64]) -> WeylElement {
+		self.element_at(self.reflect(root, &self.rho()))
+	}
+
+	/// the element mapping `ρ` to `-ρ`, of length the number of positive roots
+	pub fn longest_element(&self) -> WeylElement {
+		self.element_at(self.rho().iter().map(|x| -x).collect())
+	}
+
+	/// all the elements by a breadth first search on the orbit of `ρ`, so by increasing length
+	///
+	/// only feasible for small ranks, the Weyl group of `E8` has 696729600 elements
+	pub fn weyl_group(&self) -> Vec<WeylElement> {
+		let mut seen : HashSet<Vec<i64>> = HashSet::from([self.rho()]);
+		let mut queue = VecDeque::from([self.rho()]);
+		let mut result = Vec::new();
+		while let Some(mu) = queue.pop_front() {
+			for i in 0..self.rank() {
+				let next = self.simple_reflection(i, &mu);
+				if seen.insert(next.clone()) {
+					queue.push_back(next);
+				}
+			}
+			result.push(self.element_at(mu));
+		}
+		result
+	}
+
+	/// `|W|` as the product of the degrees `m_i + 1` of the basic invariants, where the exponents `m_i`
+	/// form the partition conjugate to the number of positive roots of each height
+	pub fn weyl_group_order(&self) -> ZZ {
+		let mut count = Vec::new();
+		for root in self.positive_roots.iter() {
+			let h = root.iter().sum::<i64>() as usize;
+			if count.len() < h + 1 {
+				count.resize(h + 1, 0);
+			}
+			count[h] += 1;
+		}
+		count.push(0);
+		(1..count.len() - 1).fold(ZZ::one(), |acc, k| acc * ZZ::from(k as i128 + 1).pow(count[k] - count[k + 1]))
+	}
+
+	/// the dimension of the irreducible representation of highest weight `λ`,
+	/// `Π <λ + ρ, α^∨> / <ρ, α^∨>` over the positive roots `α`
+	pub fn weyl_dimension(&self, weight : &[i64]) -> ZZ {
+		if !self.is_dominant(weight) {
+			panic!("the weight {:?} is not dominant", weight)
+		}
+		let shifted : Vec<i64> = weight.iter().map(|x| x + 1).collect();
+		let rho = self.rho();
+		let qq = self.positive_roots.iter().fold(QQ::one(), |acc, root| {
+			acc * QQ::from_pair(self.coroot_pairing(&shifted, root) as i128, self.coroot_pairing(&rho, root) as i128)
+		});
+		ZZ::from(qq.numerator())
+	}
+}
+
+#[test] fn positive_roots() {
+	use CartanType::*;
+	for (t, count, order) in [
+		(A(1), 1, 2), (A(3), 6, 24), (B(2), 4, 8), (B(3), 9, 48), (C(3), 9, 48), (D(4), 12, 192),
+		(E(6), 36, 51840), (E(7), 63, 2903040), (E(8), 120, 696729600), (F4, 24, 1152), (G2, 6, 12),
+	] {
+		let rs = RootSystem::new(t);
+		assert_eq!(rs.positive_roots().len(), count, "{:?}", t);
+		assert_eq!(rs.weyl_group_order(), ZZ::from(order), "{:?}", t);
+		assert_eq!(rs.longest_element().length(), count);
+	}
+	// the highest root of G2 is 3 α_1 + 2 α_2, and of E8 the one with coefficients 2 3 4 6 5 4 3 2
+	assert_eq!(RootSystem::new(G2).highest_root(), vec![3, 2]);
+	assert_eq!(RootSystem::new(E(8)).highest_root(), vec![2, 3, 4, 6, 5, 4, 3, 2]);
+	// the adjoint representation has the highest root as highest weight
+	let b3 = RootSystem::new(B(3));
+	assert_eq!(b3.root_to_weight(b3.highest_root()), vec![0, 1, 0]);
+	// in A2, ω_1 = (2 α_1 + α_2) / 3
+	let a2 = RootSystem::new(A(2));
+	assert_eq!(a2.fundamental_weights()[0], vec![QQ::from_pair(2, 3), QQ::from_pair(1, 3)]);
+}
+
+#[test] fn weyl_group() {
+	use CartanType::*;
+	for t in [A(3), B(3), G2] {
+		let rs = RootSystem::new(t);
+		let w = rs.weyl_group();
+		assert_eq!(ZZ::from(w.len() as i128), rs.weyl_group_order());
+		// the words are reduced and distinct
+		assert_eq!(w.iter().collect::<HashSet<_>>().len(), w.len());
+		assert!(w.iter().all(|x| rs.element(x.word()) == *x));
+		// reflections are involutions of odd length
+		for root in rs.positive_roots() {
+			let s = rs.reflection(root);
+			assert_eq!(s.length() % 2, 1);
+			assert_eq!(rs.multiply(&s, &s), rs.identity());
+		}
+	}
+	let a2 = RootSystem::new(A(2));
+	// s_1 s_2 s_1 = s_2 s_1 s_2 is the longest element
+	assert_eq!(a2.element(&[1, 0, 1]), a2.element(&[0, 1, 0]));
+	assert_eq!(a2.element(&[0, 0]), a2.identity());
+	assert_eq!(a2.element(&[0, 1, 0]).length(), 3);
+	let w = a2.element(&[0, 1]);
+	assert_eq!(a2.multiply(&w, &a2.inverse(&w)), a2.identity());
+	assert_eq!(a2.reflection(&[1, 1]), a2.element(&[0, 1, 0]));
+}
+
+#[test] fn weyl_dimension() {
+	use CartanType::*;
+	let dim = |t : CartanType, w : &[i64]| *RootSystem::new(t).weyl_dimension(w);
+	assert_eq!(dim(A(2), &[1, 1]), 8);
+	assert_eq!(dim(A(2), &[3, 0]), 10);
+	assert_eq!(dim(B(2), &[1, 0]), 5);
+	assert_eq!(dim(B(2), &[0, 1]), 4);
+	assert_eq!(dim(C(3), &[1, 0, 0]), 6);
+	assert_eq!(dim(D(4), &[0, 0, 0, 1]), 8);
+	assert_eq!(dim(G2, &[1, 0]), 7);
+	assert_eq!(dim(G2, &[0, 1]), 14);
+	assert_eq!(dim(F4, &[0, 0, 0, 1]), 26);
+	assert_eq!(dim(E(6), &[1, 0, 0, 0, 0, 0]), 27);
+	assert_eq!(dim(E(7), &[0, 0, 0, 0, 0, 0, 1]), 56);
+	assert_eq!(dim(E(8), &[0, 0, 0, 0, 0, 0, 0, 1]), 248);
+}
@@ -1,10 +1,13 @@
-use quote::quote;
+use quote::{format_ident, quote};
 // use syn::spanned::Spanned;
 use syn::{
     parse_macro_input, parse_quote,
     DeriveInput, 
     GenericParam, Generics, 
+    Data, Fields, Expr, ExprLit, Lit, LitStr, Token, Visibility,
 };
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 
 #[proc_macro_derive(BasicObject)]
 pub fn derive_basic_object(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -63,3 +66,623 @@ fn add_trait_bounds_copy(mut generics: Generics) -> Generics {
     }
     generics
 }
+
+/// `#[derive(MathClass)]` generates `general::MathClass::check` from the `#[invariant(...)]` attributes, tested in order, and the constructors
+/// - `try_new(fields...) -> Result<Self, Self::Error>`
+/// - `new(fields...) -> Self`, which panics with `"Type: message"`
+/// - `new_unchecked(fields...) -> Self`, which checks the invariants in debug builds only
+///
+/// an invariant is one of
+/// - `#[invariant(expr, "message", args...)]`, where `expr : bool`, failing with a `general::InvariantError`
+/// - `#[invariant(expr)]`, where `expr : Result<(), E>`
+/// - `#[invariant(path)]`, a function called as `path(self)` returning such a `Result`
+///
+/// the `MathClass::Error` is `general::InvariantError` unless `#[math_class(error = "TableauError")]`, and it should convert from all the `E`s,
+/// the constructors are private unless `#[math_class(vis = "pub")]`, and can be renamed by `#[math_class(new = "from_parts")]`, etc.
+#[proc_macro_derive(MathClass, attributes(invariant, math_class))]
+pub fn derive_math_class(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_math_class(input) {
+        Ok(expanded) => proc_macro::TokenStream::from(expanded),
+        Err(e) => proc_macro::TokenStream::from(e.to_compile_error()),
+    }
+}
+
+struct Invariant {
+    test: Expr,
+    message: Option<Punctuated<Expr, Token![,]>>,
+}
+impl Parse for Invariant {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let test = input.parse()?;
+        let message = if input.is_empty() {
+            None
+        } else {
+            input.parse::<Token![,]>()?;
+            let args = Punctuated::<Expr, Token![,]>::parse_terminated(input)?;
+            match args.first() {
+                Some(Expr::Lit(ExprLit { lit: Lit::Str(_), .. })) => {}
+                _ => return Err(input.error("expected a message as a string literal")),
+            }
+            Some(args)
+        };
+        Ok(Invariant { test, message })
+    }
+}
+
+fn expand_math_class(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut checks = Vec::new();
+    let mut vis: Visibility = Visibility::Inherited;
+    let mut new = format_ident!("new");
+    let mut try_new = format_ident!("try_new");
+    let mut new_unchecked = format_ident!("new_unchecked");
+    let mut error: syn::Type = parse_quote!(general::InvariantError);
+    for attr in &input.attrs {
+        if attr.path().is_ident("invariant") {
+            let Invariant { test, message } = attr.parse_args()?;
+            checks.push(match (message, test) {
+                (Some(message), test) => quote! {
+                    if !(#test) {
+                        return Err(::core::convert::From::from(general::InvariantError(format!(#message))));
+                    }
+                },
+                (None, Expr::Path(path)) => quote! { #path(self)?; },
+                (None, test) => quote! { #test?; },
+            });
+        } else if attr.path().is_ident("math_class") {
+            attr.parse_nested_meta(|meta| {
+                let value: LitStr = meta.value()?.parse()?;
+                if meta.path.is_ident("vis") {
+                    vis = value.parse()?;
+                } else if meta.path.is_ident("error") {
+                    error = value.parse()?;
+                } else if meta.path.is_ident("new") {
+                    new = value.parse()?;
+                } else if meta.path.is_ident("try_new") {
+                    try_new = value.parse()?;
+                } else if meta.path.is_ident("new_unchecked") {
+                    new_unchecked = value.parse()?;
+                } else {
+                    return Err(meta.error("expected `vis`, `error`, `new`, `try_new` or `new_unchecked`"));
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(syn::Error::new_spanned(name, "MathClass can only be derived for structs")),
+    };
+    let (params, construct) = match fields {
+        Fields::Named(fields) => {
+            let names: Vec<_> = fields.named.iter().map(|f| f.ident.clone().unwrap()).collect();
+            let types = fields.named.iter().map(|f| &f.ty);
+            (quote! { #(#names : #types),* }, quote! { #name { #(#names),* } })
+        }
+        Fields::Unnamed(fields) => {
+            let names: Vec<_> = (0..fields.unnamed.len()).map(|i| format_ident!("_{}", i)).collect();
+            let types = fields.unnamed.iter().map(|f| &f.ty);
+            (quote! { #(#names : #types),* }, quote! { #name ( #(#names),* ) })
+        }
+        Fields::Unit => (quote! {}, quote! { #name }),
+    };
+    let args = match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|f| f.ident.clone().unwrap());
+            quote! { #(#names),* }
+        }
+        Fields::Unnamed(fields) => {
+            let names = (0..fields.unnamed.len()).map(|i| format_ident!("_{}", i));
+            quote! { #(#names),* }
+        }
+        Fields::Unit => quote! {},
+    };
+    let label = name.to_string();
+
+    Ok(quote! {
+        impl #impl_generics general::MathClass for #name #ty_generics #where_clause {
+            type Error = #error;
+            fn check(&self) -> Result<(), #error> {
+                #(#checks)*
+                Ok(())
+            }
+        }
+        #[allow(dead_code, clippy::too_many_arguments)]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// build the value if it satisfies its invariants
+            #vis fn #try_new(#params) -> Result<Self, #error> {
+                let value = #construct;
+                general::MathClass::check(&value)?;
+                Ok(value)
+            }
+            /// build the value, panic if it does not satisfy its invariants
+            #vis fn #new(#params) -> Self {
+                match Self::#try_new(#args) {
+                    Ok(value) => value,
+                    Err(s) => panic!("{}: {}", #label, s),
+                }
+            }
+            /// build the value without checking its invariants, which is only done in debug builds
+            #vis fn #new_unchecked(#params) -> Self {
+                let value = #construct;
+                if cfg!(debug_assertions) {
+                    if let Err(s) = general::MathClass::check(&value) {
+                        panic!("{}: {}", #label, s);
+                    }
+                }
+                value
+            }
+        }
+    })
+}
+
+/// `#[derive(AddGroup)]` generates componentwise `Add`, `Sub`, `Neg` and `general::algebra::AddGroup`,
+/// every type parameter is bounded by `AddGroup`, and the struct should also derive `BasicObject`
+#[proc_macro_derive(AddGroup)]
+pub fn derive_add_group(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_add_group(input) {
+        Ok(expanded) => proc_macro::TokenStream::from(expanded),
+        Err(e) => proc_macro::TokenStream::from(e.to_compile_error()),
+    }
+}
+fn expand_add_group(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let fields = struct_fields(&input)?;
+    let generics = add_trait_bounds(input.generics.clone(), parse_quote!(general::algebra::AddGroup));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let add = componentwise(name, fields, |m| quote! { self.#m + rhs.#m });
+    let sub = componentwise(name, fields, |m| quote! { self.#m - rhs.#m });
+    let neg = componentwise(name, fields, |m| quote! { -self.#m });
+    let zero = componentwise(name, fields, |_| quote! { general::algebra::AddGroup::zero() });
+    Ok(quote! {
+        impl #impl_generics ::std::ops::Add for #name #ty_generics #where_clause {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self { #add }
+        }
+        impl #impl_generics ::std::ops::Sub for #name #ty_generics #where_clause {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self { #sub }
+        }
+        impl #impl_generics ::std::ops::Neg for #name #ty_generics #where_clause {
+            type Output = Self;
+            fn neg(self) -> Self { #neg }
+        }
+        impl #impl_generics general::algebra::AddGroup for #name #ty_generics #where_clause {
+            fn zero() -> Self { #zero }
+        }
+    })
+}
+
+/// `#[derive(Ring)]` generates the product ring: componentwise `Mul` and `general::algebra::Ring`,
+/// every type parameter is bounded by `Ring`, and the struct should also derive `AddGroup`
+#[proc_macro_derive(Ring)]
+pub fn derive_ring(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_ring(input) {
+        Ok(expanded) => proc_macro::TokenStream::from(expanded),
+        Err(e) => proc_macro::TokenStream::from(e.to_compile_error()),
+    }
+}
+fn expand_ring(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let fields = struct_fields(&input)?;
+    let generics = add_trait_bounds(input.generics.clone(), parse_quote!(general::algebra::Ring));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mul = componentwise(name, fields, |m| quote! { self.#m * rhs.#m });
+    let one = componentwise(name, fields, |_| quote! { general::algebra::Ring::one() });
+    Ok(quote! {
+        impl #impl_generics ::std::ops::Mul for #name #ty_generics #where_clause {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self { #mul }
+        }
+        impl #impl_generics general::algebra::Ring for #name #ty_generics #where_clause {
+            fn one() -> Self { #one }
+        }
+    })
+}
+
+/// `#[derive(Module)]` generates componentwise `general::algebra::ScaMul<R>` and `general::algebra::Module<R>`,
+/// for the ring given by `#[module(R)]`, or for every ring over which all the fields are modules,
+/// every type parameter is bounded by `Module<R>`, and the struct should also derive `AddGroup`
+#[proc_macro_derive(Module, attributes(module))]
+pub fn derive_module(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_module(input) {
+        Ok(expanded) => proc_macro::TokenStream::from(expanded),
+        Err(e) => proc_macro::TokenStream::from(e.to_compile_error()),
+    }
+}
+fn expand_module(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let fields = struct_fields(&input)?;
+    let mut ring: Option<syn::Type> = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("module") {
+            ring = Some(attr.parse_args()?);
+        }
+    }
+
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let ring_type: syn::Type = ring.clone().unwrap_or_else(|| parse_quote!(__R));
+    let mut generics = add_trait_bounds(input.generics.clone(), parse_quote!(general::algebra::Module<#ring_type>));
+    if ring.is_none() {
+        generics.params.insert(0, parse_quote!(__R : general::algebra::Ring));
+        // the fields of concrete types, like `QQ`, constrain the ring as well
+        let where_clause = generics.make_where_clause();
+        for field in fields.iter() {
+            let ty = &field.ty;
+            where_clause.predicates.push(parse_quote!(#ty : general::algebra::ScaMul<__R>));
+        }
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let sca_mul = componentwise(name, fields, |m| quote! { general::algebra::ScaMul::sca_mul(self.#m, sca.clone()) });
+    Ok(quote! {
+        impl #impl_generics general::algebra::ScaMul<#ring_type> for #name #ty_generics #where_clause {
+            fn sca_mul(self, sca: #ring_type) -> Self { #sca_mul }
+        }
+        impl #impl_generics general::algebra::Module<#ring_type> for #name #ty_generics #where_clause {}
+    })
+}
+
+fn struct_fields(input: &DeriveInput) -> syn::Result<&Fields> {
+    match &input.data {
+        Data::Struct(data) => Ok(&data.fields),
+        _ => Err(syn::Error::new_spanned(&input.ident, "algebraic structures can only be derived for structs")),
+    }
+}
+fn add_trait_bounds(mut generics: Generics, bound: syn::TypeParamBound) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(ref mut type_param) = *param {
+            type_param.bounds.push(bound.clone());
+        }
+    }
+    generics
+}
+/// the value of the struct whose components are given by `component(member)`
+fn componentwise(
+    name: &syn::Ident,
+    fields: &Fields,
+    component: impl Fn(&syn::Member) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let members: Vec<syn::Member> = fields.members().collect();
+    let values = members.iter().map(&component);
+    match fields {
+        Fields::Named(_) => quote! { #name { #(#members : #values),* } },
+        Fields::Unnamed(_) => quote! { #name ( #(#values),* ) },
+        Fields::Unit => quote! { #name },
+    }
+}
+
+/// an entry of a tableau literal, `None` for a hole `_`
+struct Cell {
+    value: Option<usize>,
+    span: proc_macro2::Span,
+}
+/// the rows of a tableau literal, separated by `;`, whose entries are separated by spaces
+struct Rows(Vec<(proc_macro2::Span, Vec<Cell>)>);
+impl Parse for Rows {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut rows = Vec::new();
+        while !input.is_empty() {
+            let span = input.span();
+            let mut row = Vec::new();
+            while !input.is_empty() && !input.peek(Token![;]) {
+                if input.peek(Token![_]) {
+                    let hole: Token![_] = input.parse()?;
+                    row.push(Cell { value: None, span: hole.span });
+                } else {
+                    let lit: syn::LitInt = input.parse()?;
+                    row.push(Cell { value: Some(lit.base10_parse()?), span: lit.span() });
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![;]>()?;
+            }
+            rows.push((span, row));
+        }
+        Ok(Rows(rows))
+    }
+}
+/// collect the errors, each at its own span
+fn combine(errors: Vec<syn::Error>) -> syn::Result<()> {
+    let mut errors = errors.into_iter();
+    match errors.next() {
+        None => Ok(()),
+        Some(mut first) => {
+            errors.for_each(|e| first.combine(e));
+            Err(first)
+        }
+    }
+}
+/// the errors in expression position, where consecutive `compile_error!`s need a block
+fn expression_error(e: syn::Error) -> proc_macro::TokenStream {
+    let errors = e.to_compile_error();
+    quote! { { #errors } }.into()
+}
+/// check that the entries, without holes, increase along the rows (weakly) and down the columns (strictly)
+fn check_order(rows: &[(proc_macro2::Span, Vec<Cell>)], errors: &mut Vec<syn::Error>) {
+    for (i, (_, row)) in rows.iter().enumerate() {
+        for (j, cell) in row.iter().enumerate() {
+            let Some(value) = cell.value else { continue };
+            if let Some(Cell { value: Some(left), .. }) = j.checked_sub(1).map(|j| &row[j]) {
+                if *left > value {
+                    errors.push(syn::Error::new(cell.span, format!("rows should be weakly increasing, but {} > {}", left, value)));
+                }
+            }
+            if let Some(Cell { value: Some(up), .. }) = i.checked_sub(1).and_then(|i| rows[i].1.get(j)) {
+                if *up >= value {
+                    errors.push(syn::Error::new(cell.span, format!("columns should be strictly increasing, but {} >= {}", up, value)));
+                }
+            }
+        }
+    }
+}
+/// check that the lengths are weakly decreasing, `what` names the offending rows
+fn check_partition(lengths: &[(proc_macro2::Span, usize)], what: &str, errors: &mut Vec<syn::Error>) {
+    for pair in lengths.windows(2) {
+        if pair[1].1 > pair[0].1 {
+            errors.push(syn::Error::new(pair[1].0, format!("{} should be weakly decreasing, but {} > {}", what, pair[1].1, pair[0].1)));
+        }
+    }
+}
+
+/// `tableau![1 2 3; 2 3; 4]`, a `young_tableaux::Tableau` checked at compile time
+#[proc_macro]
+pub fn tableau(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let rows = parse_macro_input!(input as Rows);
+    let mut errors = Vec::new();
+    for cell in rows.0.iter().flat_map(|(_, row)| row) {
+        if cell.value.is_none() {
+            errors.push(syn::Error::new(cell.span, "a tableau has no holes, use `skew_tableau!`"));
+        }
+    }
+    let lengths: Vec<_> = rows.0.iter().map(|(span, row)| (*span, row.len())).collect();
+    check_partition(&lengths, "the lengths of rows", &mut errors);
+    check_order(&rows.0, &mut errors);
+    if let Err(e) = combine(errors) {
+        return expression_error(e);
+    }
+    let rows = rows.0.iter().map(|(_, row)| {
+        let values = row.iter().map(|cell| cell.value.unwrap());
+        quote! { vec![#(#values),*] }
+    });
+    quote! { young_tableaux::Tableau::from(vec![#(#rows),*]) }.into()
+}
+
+/// `skew_tableau![_ _ 1 2; _ 2; 2]`, a `young_tableaux::SkewTableau` checked at compile time, where `_` marks the boxes of the inner diagram
+#[proc_macro]
+pub fn skew_tableau(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let rows = parse_macro_input!(input as Rows);
+    let mut errors = Vec::new();
+    let mut inner = Vec::new();
+    for (span, row) in &rows.0 {
+        let holes = row.iter().take_while(|cell| cell.value.is_none()).count();
+        for cell in row.iter().skip(holes).filter(|cell| cell.value.is_none()) {
+            errors.push(syn::Error::new(cell.span, "holes should come before the entries of the row"));
+        }
+        inner.push((row.first().map_or(*span, |cell| cell.span), holes));
+    }
+    let outer: Vec<_> = rows.0.iter().map(|(span, row)| (*span, row.len())).collect();
+    check_partition(&outer, "the lengths of rows", &mut errors);
+    check_partition(&inner, "the numbers of holes", &mut errors);
+    check_order(&rows.0, &mut errors);
+    if let Err(e) = combine(errors) {
+        return expression_error(e);
+    }
+    let rows = rows.0.iter().map(|(_, row)| {
+        let values = row.iter().map(|cell| match cell.value {
+            Some(value) => quote! { Some(#value) },
+            None => quote! { None },
+        });
+        quote! { vec![#(#values),*] }
+    });
+    quote! { young_tableaux::SkewTableau::from(vec![#(#rows),*]) }.into()
+}
+
+/// `diagram![5, 4, 1]`, a `young_tableaux::Diagram` checked at compile time
+#[proc_macro]
+pub fn diagram(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let parts = parse_macro_input!(input with Punctuated::<syn::LitInt, Token![,]>::parse_terminated);
+    let mut lengths = Vec::new();
+    for part in &parts {
+        match part.base10_parse::<usize>() {
+            Ok(value) => lengths.push((part.span(), value)),
+            Err(e) => return e.to_compile_error().into(),
+        }
+    }
+    let mut errors = Vec::new();
+    check_partition(&lengths, "the parts", &mut errors);
+    if let Err(e) = combine(errors) {
+        return expression_error(e);
+    }
+    let values = lengths.iter().map(|(_, value)| value);
+    quote! { young_tableaux::Diagram::from(vec![#(#values),*]) }.into()
+}
+
+/// `word![1 4 2 5]`, a `young_tableaux::Word`
+#[proc_macro]
+pub fn word(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let rows = parse_macro_input!(input as Rows);
+    let mut errors = Vec::new();
+    if rows.0.len() > 1 {
+        errors.push(syn::Error::new(rows.0[1].0, "a word has a single row"));
+    }
+    let mut values = Vec::new();
+    for cell in rows.0.iter().flat_map(|(_, row)| row) {
+        match cell.value {
+            Some(value) => values.push(value),
+            None => errors.push(syn::Error::new(cell.span, "a word has no holes")),
+        }
+    }
+    if let Err(e) = combine(errors) {
+        return expression_error(e);
+    }
+    quote! { young_tableaux::Word(vec![#(#values),*]) }.into()
+}
+
+/// a term `c * x^a * y^b ...` of a polynomial literal, with the coefficient `c = numerator / denominator`
+struct Term {
+    numerator: i128,
+    denominator: i128,
+    powers: Vec<(syn::Ident, usize)>,
+    /// where the term starts, for the overflows when the like terms are collected
+    span: proc_macro2::Span,
+}
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+impl Term {
+    fn scale(&mut self, numerator: i128, denominator: i128, span: proc_macro2::Span) -> syn::Result<()> {
+        let overflow = || syn::Error::new(span, "the coefficient overflows i128");
+        let n = self.numerator.checked_mul(numerator).ok_or_else(overflow)?;
+        let d = self.denominator.checked_mul(denominator).ok_or_else(overflow)?;
+        let g = gcd(n, d).max(1);
+        (self.numerator, self.denominator) = (n / g, d / g);
+        Ok(())
+    }
+}
+impl Parse for Term {
+    /// factors separated by `*`, each a coefficient `n` or `n/d`, or a power `x` or `x^n`
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut term = Term { numerator: 1, denominator: 1, powers: Vec::new(), span: input.span() };
+        loop {
+            if input.peek(syn::LitInt) {
+                let n: syn::LitInt = input.parse()?;
+                let mut d = 1;
+                if input.peek(Token![/]) {
+                    input.parse::<Token![/]>()?;
+                    let lit: syn::LitInt = input.parse()?;
+                    d = lit.base10_parse()?;
+                    if d == 0 {
+                        return Err(syn::Error::new(lit.span(), "0 cannot be divisor"));
+                    }
+                }
+                term.scale(n.base10_parse()?, d, n.span())?;
+            } else {
+                let var: syn::Ident = input.parse()?;
+                let mut power = 1;
+                if input.peek(Token![^]) {
+                    input.parse::<Token![^]>()?;
+                    power = input.parse::<syn::LitInt>()?.base10_parse()?;
+                }
+                term.powers.push((var, power));
+            }
+            if !input.peek(Token![*]) {
+                return Ok(term);
+            }
+            input.parse::<Token![*]>()?;
+        }
+    }
+}
+/// `poly!(context; terms)`, see `general::algebra::poly!`
+struct PolynomialLiteral {
+    /// `[x, y, z]`, or an expression for a `VarTable`
+    context: Result<Vec<syn::Ident>, Expr>,
+    terms: Vec<Term>,
+}
+impl Parse for PolynomialLiteral {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let context = if input.peek(syn::token::Bracket) {
+            let names;
+            syn::bracketed!(names in input);
+            Ok(Punctuated::<syn::Ident, Token![,]>::parse_terminated(&names)?.into_iter().collect())
+        } else {
+            Err(input.parse()?)
+        };
+        input.parse::<Token![;]>()?;
+        let mut terms = Vec::new();
+        let mut sign = 1;
+        if input.peek(Token![-]) {
+            input.parse::<Token![-]>()?;
+            sign = -1;
+        }
+        loop {
+            let span = input.span();
+            let mut term: Term = input.parse()?;
+            term.scale(sign, 1, span)?;
+            terms.push(term);
+            if input.is_empty() {
+                return Ok(PolynomialLiteral { context, terms });
+            } else if input.peek(Token![+]) {
+                input.parse::<Token![+]>()?;
+                sign = 1;
+            } else {
+                input.parse::<Token![-]>()?;
+                sign = -1;
+            }
+        }
+    }
+}
+
+/// see `general::algebra::poly!`
+#[proc_macro]
+pub fn poly(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let PolynomialLiteral { context, terms } = parse_macro_input!(input as PolynomialLiteral);
+    match context {
+        Ok(names) => {
+            // the variables are known, so the like terms are collected here
+            let mut collected: std::collections::BTreeMap<Vec<usize>, (i128, i128)> = std::collections::BTreeMap::new();
+            let mut errors = Vec::new();
+            for term in terms {
+                let mut exponent = vec![0; names.len()];
+                for (var, power) in &term.powers {
+                    match names.iter().position(|name| name == var) {
+                        Some(index) => exponent[index] += power,
+                        None => errors.push(syn::Error::new(var.span(), format!("unknown variable `{}`", var))),
+                    }
+                }
+                while exponent.last() == Some(&0) {
+                    exponent.pop();
+                }
+                let (n, d) = collected.remove(&exponent).unwrap_or((0, 1));
+                let sum = n.checked_mul(term.denominator)
+                    .zip(term.numerator.checked_mul(d))
+                    .and_then(|(a, b)| a.checked_add(b))
+                    .zip(d.checked_mul(term.denominator));
+                let Some((n, d)) = sum else {
+                    errors.push(syn::Error::new(term.span, "the coefficient overflows i128"));
+                    continue;
+                };
+                let g = gcd(n, d).max(1);
+                if n != 0 {
+                    collected.insert(exponent, (n / g, d / g));
+                }
+            }
+            if let Err(e) = combine(errors) {
+                return expression_error(e);
+            }
+            let terms = collected.into_iter().map(|(exponent, (n, d))| {
+                quote! { (vec![#(#exponent),*], general::algebra::num::QQ::from_pair(#n, #d)) }
+            });
+            quote! {
+                general::algebra::MultiPolynomial::<general::algebra::num::QQ>::from_terms([#(#terms),*])
+            }.into()
+        }
+        Err(table) => {
+            let terms = terms.into_iter().map(|Term { numerator, denominator, powers, .. }| {
+                let (names, powers): (Vec<_>, Vec<_>) = powers.into_iter().map(|(var, power)| (var.to_string(), power)).unzip();
+                quote! {
+                    vt.exponent(&[#((#names, #powers)),*]).map(|e| (e, general::algebra::num::QQ::from_pair(#numerator, #denominator)))
+                }
+            });
+            quote! {
+                {
+                    let vt : &general::algebra::VarTable = &(#table);
+                    [#(#terms),*].into_iter().collect::<Result<Vec<_>, general::algebra::UndefinedVariable>>()
+                        .map(general::algebra::MultiPolynomial::<general::algebra::num::QQ>::from_terms)
+                }
+            }.into()
+        }
+    }
+}
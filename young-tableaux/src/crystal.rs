@@ -0,0 +1,224 @@
+//! the crystal operators `e_i` and `f_i` of `GL_n` on words and tableaux, by the bracketing of `i` and `i + 1`,
+//! and the crystal graph `B(λ)`
+
+use crate::{Diagram, Tableau, Word};
+
+/// the positions of the letters `i` and `i + 1` left unbracketed, after each `i + 1` is bracketed with the nearest free `i` to its right,
+/// the free letters read `i ... i (i + 1) ... (i + 1)`
+fn unbracketed(letters : &[usize], i : usize) -> (Vec<usize>, Vec<usize>) {
+	let mut free_i = Vec::new();
+	let mut free_next = Vec::new();
+	for (pos, &letter) in letters.iter().enumerate() {
+		if letter == i + 1 {
+			free_next.push(pos);
+		} else if letter == i && free_next.pop().is_none() {
+			free_i.push(pos);
+		}
+	}
+	(free_i, free_next)
+}
+
+impl Word {
+	/// the lowering operator `f_i`, the rightmost free `i` becomes `i + 1`
+	pub fn f(&self, i : usize) -> Option<Word> {
+		let (free_i, _) = unbracketed(&self.0, i);
+		free_i.last().map(|pos| {
+			let mut w = self.clone();
+			w.0[*pos] = i + 1;
+			w
+		})
+	}
+
+	/// the raising operator `e_i`, the leftmost free `i + 1` becomes `i`
+	pub fn e(&self, i : usize) -> Option<Word> {
+		let (_, free_next) = unbracketed(&self.0, i);
+		free_next.first().map(|pos| {
+			let mut w = self.clone();
+			w.0[*pos] = i;
+			w
+		})
+	}
+
+	/// `φ_i`, how many times `f_i` can be applied
+	pub fn phi(&self, i : usize) -> usize {
+		unbracketed(&self.0, i).0.len()
+	}
+
+	/// `ε_i`, how many times `e_i` can be applied
+	pub fn epsilon(&self, i : usize) -> usize {
+		unbracketed(&self.0, i).1.len()
+	}
+
+	/// every `e_i` vanishes, i.e. `self` is a reverse lattice word
+	pub fn is_highest_weight(&self) -> bool {
+		let greatest = self.0.iter().cloned().max().unwrap_or(0);
+		(1..greatest).all(|i| self.epsilon(i) == 0)
+	}
+}
+
+impl Tableau {
+	/// the cells in the order of the word, from the bottom row to the top one, each from the left to the right
+	fn reading_cells(&self) -> Vec<(usize, usize)> {
+		self.iter_finite().enumerate().rev().flat_map(|(r, row)| (0..row.len()).map(move |c| (r, c))).collect()
+	}
+
+	fn replaced(&self, pos : usize, value : usize) -> Tableau {
+		let (r, c) = self.reading_cells()[pos];
+		let mut t = self.clone();
+		t[r][c] = value;
+		t
+	}
+
+	/// `f_i` on the word of `self`, which is again the word of a tableau of the same shape
+	pub fn f(&self, i : usize) -> Option<Tableau> {
+		let (free_i, _) = unbracketed(&self.to_word().0, i);
+		free_i.last().map(|pos| self.replaced(*pos, i + 1))
+	}
+
+	/// `e_i` on the word of `self`, which is again the word of a tableau of the same shape
+	pub fn e(&self, i : usize) -> Option<Tableau> {
+		let (_, free_next) = unbracketed(&self.to_word().0, i);
+		free_next.first().map(|pos| self.replaced(*pos, i))
+	}
+
+	/// `φ_i` of the word of `self`
+	pub fn phi(&self, i : usize) -> usize {
+		self.to_word().phi(i)
+	}
+
+	/// `ε_i` of the word of `self`
+	pub fn epsilon(&self, i : usize) -> usize {
+		self.to_word().epsilon(i)
+	}
+
+	/// the highest weight elements are the tableaux whose row `r` is filled with `r + 1`
+	pub fn is_highest_weight(&self) -> bool {
+		self.to_word().is_highest_weight()
+	}
+}
+
+/// the crystal graph, the edges `(b, f_i(b), i)` between the indices of the vertices
+#[derive(Debug, Clone)]
+pub struct CrystalGraph {
+	vertices : Vec<Tableau>,
+	edges : Vec<(usize, usize, usize)>,
+}
+impl CrystalGraph {
+	pub fn vertices(&self) -> &[Tableau] {
+		&self.vertices
+	}
+
+	pub fn edges(&self) -> &[(usize, usize, usize)] {
+		&self.edges
+	}
+
+	/// the graph in the DOT language of Graphviz, the edges labelled by `i`
+	pub fn to_dot(&self) -> String {
+		let mut dot = String::from("digraph crystal {\n");
+		for (k, t) in self.vertices.iter().enumerate() {
+			dot.push_str(&format!("\t{} [label=\"{}\"];\n", k, format!("{}", t).trim_end().replace('\n', "\\n")));
+		}
+		for (from, to, i) in self.edges.iter() {
+			dot.push_str(&format!("\t{} -> {} [label=\"{}\"];\n", from, to, i));
+		}
+		dot.push('}');
+		dot
+	}
+}
+
+impl Diagram {
+	/// the crystal graph `B(λ)` of `GL_n`, on the semistandard tableaux of shape `λ` with entries in `1..=n`,
+	/// with the edges of `f_1, ..., f_{n - 1}`
+	pub fn crystal_graph(&self, n : usize) -> CrystalGraph {
+		let vertices : Vec<Tableau> = Tableau::semistandard(self, n).collect();
+		let mut edges = Vec::new();
+		for (k, t) in vertices.iter().enumerate() {
+			for i in 1..n {
+				if let Some(lower) = t.f(i) {
+					let to = vertices.iter().position(|u| *u == lower).expect("f_i keeps the shape and the entries at most n");
+					edges.push((k, to, i));
+				}
+			}
+		}
+		CrystalGraph { vertices, edges }
+	}
+}
+#[test] fn crystal() {
+	use general::MathClass;
+
+	let w = Word(vec![1, 2, 1, 1, 2, 2, 1]);
+	// the free letters are `1 . . 1 2 . .`
+	assert_eq!((w.phi(1), w.epsilon(1)), (2, 1));
+	assert_eq!(w.f(1), Some(Word(vec![1, 2, 1, 2, 2, 2, 1])));
+	assert_eq!(w.e(1), Some(Word(vec![1, 2, 1, 1, 1, 2, 1])));
+	assert_eq!(Word(vec![2, 1]).f(1), None);
+	assert!(Word(vec![3, 2, 1, 2, 1, 1]).is_highest_weight());
+	assert!(!Word(vec![1, 2, 2]).is_highest_weight());
+
+	// the operators commute with the insertion, all the words of length 5 in 1, 2, 3
+	for k in 0..3usize.pow(5) {
+		let w = Word((0..5).map(|p| k / 3usize.pow(p) % 3 + 1).collect());
+		let t = w.to_tableau();
+		for i in 1..3 {
+			assert_eq!(w.f(i).map(|v| v.to_tableau()), t.f(i));
+			assert_eq!(w.e(i).map(|v| v.to_tableau()), t.e(i));
+			assert_eq!((w.phi(i), w.epsilon(i)), (t.phi(i), t.epsilon(i)));
+			if let Some(v) = w.f(i) {
+				assert_eq!(v.e(i), Some(w.clone()));
+			}
+		}
+		assert_eq!(w.is_highest_weight(), w.is_reverse_lattice());
+	}
+
+	for (shape, n) in [(vec![2, 1], 3), (vec![3, 1], 3), (vec![2, 2, 1], 4)] {
+		let shape = Diagram::from(shape);
+		let graph = shape.crystal_graph(n);
+		assert_eq!(graph.vertices().len() as i128, *shape.count_semistandard_tableaux(n));
+		assert!(graph.vertices().iter().all(|t| t.check().is_ok() && t.shape() == shape));
+		// `B(λ)` is connected with a single highest weight element
+		let highest : Vec<&Tableau> = graph.vertices().iter().filter(|t| t.is_highest_weight()).collect();
+		let rows : Vec<Vec<usize>> = shape.iter_finite().enumerate().map(|(r, len)| vec![r + 1; *len]).collect();
+		assert_eq!(highest, vec![&Tableau::from(rows)]);
+		let mut reached = vec![graph.vertices().iter().position(|t| t.is_highest_weight()).unwrap()];
+		let mut k = 0;
+		while k < reached.len() {
+			for (from, to, _) in graph.edges() {
+				if *from == reached[k] && !reached.contains(to) {
+					reached.push(*to);
+				}
+			}
+			k += 1;
+		}
+		assert_eq!(reached.len(), graph.vertices().len());
+		// one `f_i` edge out of each vertex with `φ_i > 0`, and the `i`-strings have length `φ_i + ε_i`
+		for t in graph.vertices() {
+			for i in 1..n {
+				let out = graph.edges().iter().filter(|(from, _, j)| graph.vertices()[*from] == *t && *j == i).count();
+				assert_eq!(out, usize::from(t.phi(i) > 0));
+				let (mut top, mut length) = (t.clone(), 0);
+				while let Some(u) = top.e(i) {
+					top = u;
+				}
+				while let Some(u) = top.f(i) {
+					top = u;
+					length += 1;
+				}
+				assert_eq!(length, t.phi(i) + t.epsilon(i));
+			}
+		}
+		// the weight multiplicities are the Kostka numbers
+		for t in graph.vertices() {
+			let mut content = t.content_1();
+			content.resize(n, 0);
+			let multiplicity = graph.vertices().iter().filter(|u| {
+				let mut c = u.content_1();
+				c.resize(n, 0);
+				c == content
+			}).count();
+			assert_eq!(multiplicity as i128, *shape.kostka(&content));
+		}
+	}
+
+	let dot = Diagram::from(vec![1]).crystal_graph(2).to_dot();
+	assert_eq!(dot, "digraph crystal {\n\t0 [label=\"1\"];\n\t1 [label=\"2\"];\n\t0 -> 1 [label=\"1\"];\n}");
+}
@@ -0,0 +1,137 @@
+//! what goes wrong when building or operating on diagrams, tableaux and matrices
+
+use std::fmt;
+use std::error::Error;
+
+use general::InvariantError;
+
+/// the row `row` has `length` boxes, more than the `above` boxes of the row above it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeError {
+	pub row : usize,
+	pub length : usize,
+	pub above : usize,
+}
+impl fmt::Display for ShapeError {
+	fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "young diagram should be weakly decreasing, but row {} has {} > {} boxes", self.row, self.length, self.above)
+	}
+}
+impl Error for ShapeError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+	/// rows should be weakly increasing
+	Row,
+	/// columns should be strictly increasing
+	Column,
+}
+/// the entry at `(row, col)` is out of order with the previous entry in its row or column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderViolation {
+	pub row : usize,
+	pub col : usize,
+	pub direction : Direction,
+}
+impl fmt::Display for OrderViolation {
+	fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.direction {
+			Direction::Row => write!(f, "rows in tableau should be weakly increasing, but not at ({}, {})", self.row, self.col),
+			Direction::Column => write!(f, "cols in tableau should be strictly increasing, but not at ({}, {})", self.row, self.col),
+		}
+	}
+}
+impl Error for OrderViolation {}
+
+/// the row `row` of the inner diagram has `inner` boxes, more than the `outer` boxes of the outer diagram
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContainmentError {
+	pub row : usize,
+	pub inner : usize,
+	pub outer : usize,
+}
+impl fmt::Display for ContainmentError {
+	fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "the inner Diagram should be \"inside\" of outer Diagram, but row {} has {} > {} boxes", self.row, self.inner, self.outer)
+	}
+}
+impl Error for ContainmentError {}
+
+/// there are `len` entries for a `m × n` matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutMismatch {
+	pub m : usize,
+	pub n : usize,
+	pub len : usize,
+}
+impl fmt::Display for LayoutMismatch {
+	fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "size of matrix differs from the layout, {} entries for {} × {}", self.len, self.m, self.n)
+	}
+}
+impl Error for LayoutMismatch {}
+
+/// all the errors of this crate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableauError {
+	Shape(ShapeError),
+	Order(OrderViolation),
+	Containment(ContainmentError),
+	Layout(LayoutMismatch),
+	/// there is nothing to pop or bump out of this row
+	EmptyRow(usize),
+	/// there is no corner at the end of this row, to slide into or out of
+	NoCorner(usize),
+	/// the other criteria
+	Invariant(InvariantError),
+}
+impl fmt::Display for TableauError {
+	fn fmt(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TableauError::Shape(e) => e.fmt(f),
+			TableauError::Order(e) => e.fmt(f),
+			TableauError::Containment(e) => e.fmt(f),
+			TableauError::Layout(e) => e.fmt(f),
+			TableauError::EmptyRow(row) => write!(f, "the row {} is empty", row),
+			TableauError::NoCorner(row) => write!(f, "no corner at the end of the row {}", row),
+			TableauError::Invariant(e) => e.fmt(f),
+		}
+	}
+}
+impl Error for TableauError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			TableauError::Shape(e) => Some(e),
+			TableauError::Order(e) => Some(e),
+			TableauError::Containment(e) => Some(e),
+			TableauError::Layout(e) => Some(e),
+			TableauError::Invariant(e) => Some(e),
+			TableauError::EmptyRow(_) | TableauError::NoCorner(_) => None,
+		}
+	}
+}
+impl From<ShapeError> for TableauError {
+	fn from(e : ShapeError) -> TableauError {
+		TableauError::Shape(e)
+	}
+}
+impl From<OrderViolation> for TableauError {
+	fn from(e : OrderViolation) -> TableauError {
+		TableauError::Order(e)
+	}
+}
+impl From<ContainmentError> for TableauError {
+	fn from(e : ContainmentError) -> TableauError {
+		TableauError::Containment(e)
+	}
+}
+impl From<LayoutMismatch> for TableauError {
+	fn from(e : LayoutMismatch) -> TableauError {
+		TableauError::Layout(e)
+	}
+}
+impl From<InvariantError> for TableauError {
+	fn from(e : InvariantError) -> TableauError {
+		TableauError::Invariant(e)
+	}
+}
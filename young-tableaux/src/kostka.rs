@@ -0,0 +1,128 @@
+//! the Kostka numbers `K_{λμ}`, the numbers of semistandard tableaux of shape `λ` and content `μ`
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use general::algebra::{AddGroup, Ring};
+use general::algebra::num::ZZ;
+
+use crate::{Diagram, PartitionConstraints, PartitionOrder};
+
+/// the shapes `ν` such that `shape / ν` is a horizontal strip of `k` boxes, at most one box in each column
+fn horizontal_strips(shape : &[usize], k : usize) -> Vec<Vec<usize>> {
+	fn choose(shape : &[usize], k : usize, row : usize, inner : &mut Vec<usize>, strips : &mut Vec<Vec<usize>>) {
+		if row == shape.len() {
+			if k == 0 {
+				let length = inner.iter().take_while(|r| **r > 0).count();
+				strips.push(inner[..length].to_vec());
+			}
+			return;
+		}
+		// the row can lose the boxes which are not above the next row
+		let below = shape.get(row + 1).cloned().unwrap_or(0);
+		for removed in 0..=k.min(shape[row] - below) {
+			inner.push(shape[row] - removed);
+			choose(shape, k - removed, row + 1, inner, strips);
+			inner.pop();
+		}
+	}
+	let mut strips = Vec::new();
+	choose(shape, k, 0, &mut Vec::new(), &mut strips);
+	strips
+}
+
+/// remove the horizontal strip of the greatest entries, which are `content.len()`, one after another
+fn kostka_by_strips(shape : Vec<usize>, content : &[usize], memo : &mut HashMap<(Vec<usize>, usize), ZZ>) -> ZZ {
+	let Some((&k, rest)) = content.split_last() else {
+		return ZZ::one();
+	};
+	// the columns are strictly increasing
+	if shape.len() > content.len() {
+		return ZZ::zero();
+	}
+	// the zeros of the content leave the shape as it is, so the entries left are a part of the key
+	let key = (shape, content.len());
+	if let Some(k) = memo.get(&key) {
+		return *k;
+	}
+	let count = horizontal_strips(&key.0, k).into_iter().fold(ZZ::zero(), |acc, inner| acc + kostka_by_strips(inner, rest, memo));
+	memo.insert(key, count);
+	count
+}
+
+impl Diagram {
+	/// `K_{λμ}` for `λ = self` and `μ = content`, where the content may be any composition
+	pub fn kostka(&self, content : &[usize]) -> ZZ {
+		if self.n() != content.iter().sum::<usize>() {
+			return ZZ::zero();
+		}
+		kostka_by_strips(self.iter_finite().cloned().collect(), content, &mut HashMap::new())
+	}
+
+	/// the partitions of `n` from `(n)` down in the reverse lexicographic order, and `K_{λμ}` for `λ` and `μ` among them,
+	/// upper unitriangular as `K_{λμ} = 0` unless `λ` dominates `μ`
+	pub fn kostka_matrix(n : usize) -> (Vec<Diagram>, Vec<Vec<ZZ>>) {
+		let partitions : Vec<Diagram> = Diagram::partitions_with(n, PartitionConstraints::default(), PartitionOrder::RevLex).collect();
+		let mut matrix = vec![vec![ZZ::zero(); partitions.len()]; partitions.len()];
+		for (j, mu) in partitions.iter().enumerate() {
+			let content : Vec<usize> = mu.iter_finite().cloned().collect();
+			// the shapes met along the way are shared by the column
+			let mut memo = HashMap::new();
+			for (i, lambda) in partitions.iter().enumerate().take(j + 1) {
+				matrix[i][j] = kostka_by_strips(lambda.iter_finite().cloned().collect(), &content, &mut memo);
+			}
+		}
+		(partitions, matrix)
+	}
+
+	/// the inverse of the Kostka matrix, in the same order, upper unitriangular as well
+	pub fn inverse_kostka_matrix(n : usize) -> (Vec<Diagram>, Vec<Vec<ZZ>>) {
+		let (partitions, kostka) = Diagram::kostka_matrix(n);
+		let len = partitions.len();
+		let mut inverse = vec![vec![ZZ::zero(); len]; len];
+		// back substitution, `K^{-1}_{ij} = - Σ_{i < k <= j} K_{ik} K^{-1}_{kj}`
+		for i in (0..len).rev() {
+			inverse[i] = (0..len).map(|j| match j.cmp(&i) {
+				Ordering::Less => ZZ::zero(),
+				Ordering::Equal => ZZ::one(),
+				Ordering::Greater => -(i + 1..=j).fold(ZZ::zero(), |acc, k| acc + kostka[i][k] * inverse[k][j]),
+			}).collect();
+		}
+		(partitions, inverse)
+	}
+}
+#[test] fn kostka() {
+	use crate::{Tableau, StandardTableau};
+	let shape = Diagram::from(vec![3, 2]);
+	assert_eq!(shape.kostka(&[2, 2, 1]), ZZ::from(2));
+	assert_eq!(shape.kostka(&[1, 2, 1, 1]), ZZ::from(3));
+	assert_eq!(shape.kostka(&[4, 1]), ZZ::from(0));
+	assert_eq!(shape.kostka(&[2, 2]), ZZ::from(0));
+	assert_eq!(Diagram::new().kostka(&[]), ZZ::from(1));
+	for shape in [vec![3, 2, 1], vec![4, 2], vec![2, 2, 1, 1]] {
+		let shape = Diagram::from(shape);
+		for content in [vec![2, 2, 2], vec![1, 3, 0, 2], vec![3, 1, 1, 1], vec![1; 6]] {
+			assert_eq!(*shape.kostka(&content), Tableau::with_content(&shape, &content).count() as i128);
+		}
+	}
+	// `K_{λ, 1^n} = f^λ`, far beyond enumeration
+	let shape = Diagram::from(vec![6, 5, 4, 3, 2, 1]);
+	assert_eq!(shape.kostka(&[1; 21]), shape.count_standard_tableaux());
+	assert_eq!(*Diagram::from(vec![2, 2]).kostka(&[1; 4]), StandardTableau::of_shape(&Diagram::from(vec![2, 2])).count() as i128);
+
+	let (partitions, matrix) = Diagram::kostka_matrix(3);
+	assert_eq!(partitions, vec![Diagram::from(vec![3]), Diagram::from(vec![2, 1]), Diagram::from(vec![1, 1, 1])]);
+	assert_eq!(matrix, [[1, 1, 1], [0, 1, 2], [0, 0, 1]].map(|row| row.map(ZZ::from).to_vec()).to_vec());
+	let (_, inverse) = Diagram::inverse_kostka_matrix(3);
+	assert_eq!(inverse, [[1, -1, 1], [0, 1, -2], [0, 0, 1]].map(|row| row.map(ZZ::from).to_vec()).to_vec());
+
+	let (partitions, matrix) = Diagram::kostka_matrix(7);
+	let (_, inverse) = Diagram::inverse_kostka_matrix(7);
+	for i in 0..partitions.len() {
+		for j in 0..partitions.len() {
+			let product = (0..partitions.len()).fold(ZZ::zero(), |acc, k| acc + matrix[i][k] * inverse[k][j]);
+			assert_eq!(product, if i == j { ZZ::one() } else { ZZ::zero() });
+			assert_eq!(matrix[i][j].is_zero(), !partitions[i].dominates(&partitions[j]));
+		}
+	}
+}
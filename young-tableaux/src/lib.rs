@@ -4,11 +4,60 @@ use std::ops::Mul;
 
 use general::MathClass;
 
+// lets the literal macros, which expand to `young_tableaux::...`, be used in this crate
+extern crate self as young_tableaux;
+
+pub mod error;
+pub use error::TableauError;
+
 mod tableaux;
-pub use tableaux::{Filling, Numbering, Tableau, StandardTableau, SkewTableau};
+pub use tableaux::{Diagram, SkewDiagram, Filling, Numbering, Tableau, StandardTableau, SkewTableau};
 
 mod words;
 pub use words::Word;
+mod plactic;
+
+mod partitions;
+pub use partitions::{Partitions, PartitionConstraints, PartitionOrder};
+
+mod orders;
+pub use orders::DiagramOrder;
+
+mod kostka;
+mod schur;
+
+mod symmetric;
+pub use symmetric::{SymmetricFunction, Basis};
+
+mod littlewood_richardson;
+
+mod crystal;
+pub use crystal::CrystalGraph;
+
+/// a `Tableau` whose shape and order are checked at compile time
+/// ```
+/// use young_tableaux::{tableau, Tableau};
+/// assert_eq!(tableau![1 2 3; 2 3; 4], Tableau::from(vec![vec![1, 2, 3], vec![2, 3], vec![4]]));
+/// ```
+/// ```compile_fail
+/// young_tableaux::tableau![1 2; 2 3 4];
+/// ```
+pub use general_marco::tableau;
+/// a `SkewTableau` whose shape and order are checked at compile time, the holes `_` fill the inner diagram
+/// ```
+/// use young_tableaux::{skew_tableau, word};
+/// assert_eq!(skew_tableau![_ _ 1 2; _ 2; 2].to_word(), word![2 2 1 2]);
+/// ```
+/// ```compile_fail
+/// young_tableaux::skew_tableau![_ 1; 1 1];
+/// ```
+pub use general_marco::skew_tableau;
+/// a `Diagram` whose parts are checked to be weakly decreasing at compile time
+/// ```compile_fail
+/// young_tableaux::diagram![1, 2];
+/// ```
+pub use general_marco::diagram;
+pub use general_marco::word;
 
 impl Mul for Tableau {
 	type Output = Self;
@@ -29,6 +78,18 @@ impl Mul for Tableau {
 	assert_eq!((lhs * rhs).to_word(), Word(vec![4,6,1,2,3,3,3,3,4]));
 }
 
+#[test] fn literals() {
+	assert_eq!(tableau![1 1 2; 2 3; 4;], Tableau::from(vec![vec![1,1,2], vec![2,3], vec![4]]));
+	assert_eq!(skew_tableau![_ _ 1 2; _ 2; 2], SkewTableau::from(vec![
+		vec![   None,    None, Some(1), Some(2)],
+		vec![   None, Some(2)],
+		vec![Some(2)],
+	]));
+	assert_eq!(diagram![5, 4, 1], Diagram::from(vec![5,4,1]));
+	assert_eq!(word![1 4 2 5], Word(vec![1,4,2,5]));
+	assert!(tableau![].is_empty());
+}
+
 impl Word {
 	pub fn to_skew_tableau(&self) -> SkewTableau {
 		// + scan
@@ -0,0 +1,102 @@
+//! the Littlewood–Richardson coefficients `c^ν_{λμ}`, the coefficients of `s_λ s_μ = Σ c^ν_{λμ} s_ν`
+
+use general::algebra::AddGroup;
+use general::algebra::num::ZZ;
+
+use crate::{Diagram, SkewDiagram, SkewTableau, SymmetricFunction, Basis, Word, DiagramOrder};
+
+impl Word {
+	/// read from the right to the left, each `i` has been met at least as many times as `i + 1` so far,
+	/// i.e. the reversed word is a lattice (Yamanouchi) word
+	pub fn is_reverse_lattice(&self) -> bool {
+		let mut met : Vec<usize> = Vec::new();
+		for &letter in self.0.iter().rev() {
+			if letter == 0 {
+				return false;
+			}
+			if met.len() < letter {
+				met.resize(letter, 0);
+			}
+			met[letter - 1] += 1;
+			if letter > 1 && met[letter - 1] > met[letter - 2] {
+				return false;
+			}
+		}
+		true
+	}
+}
+
+impl SkewTableau {
+	/// a Littlewood–Richardson skew tableau, whose word is a reverse lattice word
+	pub fn is_littlewood_richardson(&self) -> bool {
+		self.to_word().is_reverse_lattice()
+	}
+}
+
+impl Diagram {
+	/// `c^ν_{λμ}` for `λ = self`, as the number of Littlewood–Richardson skew tableaux of shape `ν / λ` and content `μ`
+	pub fn littlewood_richardson(&self, mu : &Diagram, nu : &Diagram) -> ZZ {
+		if !self.le_in(nu, DiagramOrder::Containment) || self.n() + mu.n() != nu.n() {
+			return ZZ::zero();
+		}
+		let content : Vec<usize> = mu.iter_finite().cloned().collect();
+		let count = SkewTableau::with_content(&SkewDiagram::from(self.clone(), nu.clone()), &content)
+			.filter(SkewTableau::is_littlewood_richardson)
+			.count();
+		ZZ::from(count as i128)
+	}
+
+	/// `s_λ s_μ` for `λ = self`, expanded in the Schur functions by the Littlewood–Richardson rule
+	pub fn littlewood_richardson_product(&self, mu : &Diagram) -> SymmetricFunction<ZZ> {
+		SymmetricFunction::from_terms(Basis::Schur, Diagram::partitions(self.n() + mu.n())
+			.filter(|nu| self <= nu && mu <= nu)
+			.map(|nu| {
+				let c = self.littlewood_richardson(mu, &nu);
+				(nu, c)
+			})
+		)
+	}
+}
+#[test] fn littlewood_richardson() {
+	use crate::{Tableau, StandardTableau};
+	let d = |v : Vec<usize>| Diagram::from(v);
+
+	assert!(Word(vec![1, 2, 1, 1]).is_reverse_lattice());
+	assert!(Word(vec![3, 2, 1, 2, 1, 1]).is_reverse_lattice());
+	assert!(!Word(vec![1, 2]).is_reverse_lattice());
+	assert!(!Word(vec![2, 1, 3, 1]).is_reverse_lattice());
+	assert!(Word(vec![]).is_reverse_lattice());
+
+	assert_eq!(d(vec![2, 1]).littlewood_richardson(&d(vec![2, 1]), &d(vec![3, 2, 1])), ZZ::from(2));
+	assert_eq!(d(vec![2, 1]).littlewood_richardson(&d(vec![2, 1]), &d(vec![4, 2])), ZZ::from(1));
+	assert_eq!(d(vec![2, 1]).littlewood_richardson(&d(vec![2, 1]), &d(vec![2, 2, 2])), ZZ::from(1));
+	assert_eq!(d(vec![2, 1]).littlewood_richardson(&d(vec![2, 1]), &d(vec![6])), ZZ::from(0));
+	assert_eq!(d(vec![3, 1]).littlewood_richardson(&d(vec![1]), &d(vec![2, 2, 1])), ZZ::from(0));
+	assert_eq!(d(vec![4, 2, 1]).littlewood_richardson(&d(vec![3, 2, 1]), &d(vec![6, 4, 2, 1])), ZZ::from(4));
+	// Pieri, `s_λ h_k` is the sum over the horizontal strips of `k` boxes
+	let pieri = d(vec![2, 1]).littlewood_richardson_product(&d(vec![2]));
+	assert_eq!(pieri.terms().count(), 4);
+	assert!(pieri.terms().all(|(_, c)| *c == ZZ::from(1)));
+
+	for (lambda, mu) in [(vec![2, 1], vec![2, 1]), (vec![3, 1], vec![2, 2]), (vec![2, 1, 1], vec![2]), (vec![3, 2], vec![1, 1, 1])] {
+		let (lambda, mu) = (d(lambda), d(mu));
+		let product = lambda.littlewood_richardson_product(&mu);
+		assert_eq!(product, SymmetricFunction::s(&lambda) * SymmetricFunction::s(&mu));
+		assert_eq!(product, mu.littlewood_richardson_product(&lambda));
+
+		// Fulton §5.1, the skew tableaux on `ν / λ` rectifying to a fixed `U_0` of shape `μ`,
+		// and the skew tableaux on `λ * μ` rectifying to a fixed `V_0` of shape `ν`, are both counted by `c^ν_{λμ}`
+		let u0 = StandardTableau::of_shape(&mu).next().unwrap().to_tableau();
+		for (nu, c) in product.terms() {
+			let rectified = SkewTableau::with_content(&SkewDiagram::from(lambda.clone(), nu.clone()), &vec![1; mu.n()])
+				.filter(|s| s.clone().rect() == u0)
+				.count();
+			assert_eq!(ZZ::from(rectified as i128), *c);
+			let v0 : Tableau = StandardTableau::of_shape(&nu).last().unwrap().to_tableau();
+			let rectified = SkewTableau::with_content(&(lambda.clone() * mu.clone()), &vec![1; nu.n()])
+				.filter(|s| s.clone().rect() == v0)
+				.count();
+			assert_eq!(ZZ::from(rectified as i128), *c);
+		}
+	}
+}
@@ -3,6 +3,7 @@ use std::cmp::{max, Ordering};
 use std::ops::{Index, IndexMut};
 
 use super::{MathClass};
+use crate::error::{TableauError, LayoutMismatch};
 
 
 // ----------------------------------------------------------------
@@ -32,6 +33,15 @@ impl fmt::Display for Layout {
 	assert_eq!(format!("{}", l), "■ ■ \n■ ■ \n■ ■ \n");
 }
 impl Layout {
+	/// whether `len` entries make up a matrix of this layout
+	fn fits(&self, len : usize) -> Result<(), LayoutMismatch> {
+		if len == self.m * self.n {
+			Ok(())
+		} else {
+			Err(LayoutMismatch { m : self.m, n : self.n, len })
+		}
+	}
+
 	pub fn index_of(&self, row : usize, col : usize) -> usize {
 		col + row * self.n
 	}
@@ -117,20 +127,13 @@ impl Layout {
 /// |  1 2 3 4
 /// m  3 4 3 2
 /// |  2 3 4 5
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, MathClass)]
+#[invariant(self.layout.fits(self.inner.len()))]
+#[math_class(error = "TableauError", new = "from_parts", try_new = "try_from_parts")]
 pub struct Matrix {
 	inner : Vec<usize>,
 	layout : Layout,
 }
-impl MathClass for Matrix {
-	fn check(&self) -> Result<(), String> {
-		if self.inner.len() == self.layout.m * self.layout.n {
-			Ok(())
-		} else {
-			Err("Matrix: size of matrix differs from the layout".into())
-		}
-	}
-}
 impl Matrix {
 	pub fn new() -> Matrix {
 		Matrix {
@@ -140,14 +143,11 @@ impl Matrix {
 	}
 
 	pub fn from(v : Vec<usize>, m : usize, n : usize) -> Matrix {
-		let matrix = Matrix {
-			inner : v,
-			layout : Layout {n, m},
-		};
-		if let Err(s) = matrix.check() {
-			panic!("{}", s);
-		}
-		matrix
+		Matrix::from_parts(v, Layout {n, m})
+	}
+
+	pub fn try_from(v : Vec<usize>, m : usize, n : usize) -> Result<Matrix, TableauError> {
+		Matrix::try_from_parts(v, Layout {n, m})
 	}
 
 	pub fn from_layout(m : usize, n : usize) -> Matrix {
@@ -222,40 +222,31 @@ type Range = std::ops::Range<usize>;
 /// m  --- | --- | ---
 /// |  3   | 5   | 6
 ///      4 |     |   7
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, MathClass)]
+#[invariant(self.layout.fits(self.inner.len()))]
+#[invariant(self.is_well_marked(), "error on the markup of Balls")]
+#[math_class(error = "TableauError", new = "from_parts", try_new = "try_from_parts")]
 pub struct BallMatrix {
 	inner : Vec<Range>,
 	layout : Layout,
 }
-impl MathClass for BallMatrix {
-	fn check(&self) -> Result<(), String> {
-		if self.inner.len() != self.layout.m * self.layout.n {
-			Err("BallMatrix: size of matrix differs from the layout".into())
-		} else {
-			for index in 0..self.inner.len() {
-				if !match (self.layout.west_one(index), self.layout.north_one(index)) {
-					(None, None) => true,
-					(Some(west_one), None) => self.inner[index].start == self.inner[west_one].end,
-					(None, Some(north_one)) => self.inner[index].start == self.inner[north_one].end,
-					(Some(west_one), Some(north_one)) => self.inner[index].start == max(self.inner[west_one].end, self.inner[north_one].end),
-				} {
-					return Err("BallMatrix: error on the markup of Balls ".into())
-				}
-			}
-			Ok(())
-		}
-	}
-}
 impl BallMatrix {
 	pub fn from(v : Vec<(usize, usize)>, m : usize, n : usize) -> BallMatrix {
-		let matrix = BallMatrix {
-			inner : v.into_iter().map(|(a, b)| {a..b}).collect(),
-			layout : Layout {n, m},
-		};
-		if let Err(s) = matrix.check() {
-			panic!("{}", s);
-		}
-		matrix
+		BallMatrix::from_parts(v.into_iter().map(|(a, b)| {a..b}).collect(), Layout {n, m})
+	}
+
+	pub fn try_from(v : Vec<(usize, usize)>, m : usize, n : usize) -> Result<BallMatrix, TableauError> {
+		BallMatrix::try_from_parts(v.into_iter().map(|(a, b)| {a..b}).collect(), Layout {n, m})
+	}
+
+	/// each ball starts where the balls to its west and to its north end
+	fn is_well_marked(&self) -> bool {
+		(0..self.inner.len()).all(|index| match (self.layout.west_one(index), self.layout.north_one(index)) {
+			(None, None) => true,
+			(Some(west_one), None) => self.inner[index].start == self.inner[west_one].end,
+			(None, Some(north_one)) => self.inner[index].start == self.inner[north_one].end,
+			(Some(west_one), Some(north_one)) => self.inner[index].start == max(self.inner[west_one].end, self.inner[north_one].end),
+		})
 	}
 	pub fn is_empty(&self) -> bool {
 		self.inner.is_empty() || self.inner.last().unwrap().end - self.inner[0].start == 0 // ?
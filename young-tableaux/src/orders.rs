@@ -0,0 +1,218 @@
+//! the orders on diagrams: containment, dominance and the lexicographic order
+
+use std::cmp::Ordering;
+
+use general::poset::Poset;
+
+use crate::{Diagram, PartitionConstraints, PartitionOrder};
+
+/// the two lattices on diagrams
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagramOrder {
+	/// Young's lattice, `μ <= λ` when `μ_i <= λ_i` for all `i`, the derived `PartialOrd`
+	#[default]
+	Containment,
+	/// `μ <= λ` when `μ_1 + ... + μ_k <= λ_1 + ... + λ_k` for all `k`, among the diagrams with the same number of boxes
+	Dominance,
+}
+
+impl Diagram {
+	/// the partial sums `λ_1, λ_1 + λ_2, ...`, up to `length` of them
+	fn partial_sums(&self, length : usize) -> Vec<usize> {
+		self.iter().take(length).scan(0, |acc, x| {
+			*acc += *x;
+			Some(*acc)
+		}).collect()
+	}
+
+	/// the diagram of the partial sums `sums`
+	fn from_partial_sums(sums : &[usize]) -> Diagram {
+		Diagram::from(sums.iter().scan(0, |prev, s| {
+			let part = s - *prev;
+			*prev = *s;
+			Some(part)
+		}).collect())
+	}
+
+	/// whether `self` dominates `other`, both of them having the same number of boxes
+	pub fn dominates(&self, other : &Diagram) -> bool {
+		let length = self.abs().max(other.abs());
+		self.n() == other.n() && self.partial_sums(length).iter().zip(other.partial_sums(length)).all(|(s, o)| *s >= o)
+	}
+
+	/// the dominance order, `None` when the diagrams are incomparable or have different numbers of boxes
+	pub fn dominance_cmp(&self, other : &Diagram) -> Option<Ordering> {
+		match (self.dominates(other), other.dominates(self)) {
+			(true, true) => Some(Ordering::Equal),
+			(true, false) => Some(Ordering::Greater),
+			(false, true) => Some(Ordering::Less),
+			(false, false) => None,
+		}
+	}
+
+	/// the lexicographic order on the rows, a total order refining the dominance order
+	pub fn lex_cmp(&self, other : &Diagram) -> Ordering {
+		self.iter_finite().cmp(other.iter_finite())
+	}
+
+	/// whether `self <= other` in `order`
+	pub fn le_in(&self, other : &Diagram, order : DiagramOrder) -> bool {
+		match order {
+			DiagramOrder::Containment => self <= other,
+			DiagramOrder::Dominance => other.dominates(self),
+		}
+	}
+
+	fn same_size(&self, other : &Diagram) {
+		if self.n() != other.n() {
+			panic!("Diagram: dominance compares diagrams of the same size, but {} != {}", self.n(), other.n())
+		}
+	}
+
+	/// the greatest lower bound in `order`, panics for the dominance order of diagrams of different sizes
+	pub fn meet(&self, other : &Diagram, order : DiagramOrder) -> Diagram {
+		match order {
+			DiagramOrder::Containment => Diagram::from(self.iter().zip(other.iter()).take(self.abs().min(other.abs())).map(|(s, o)| *s.min(o)).collect()),
+			DiagramOrder::Dominance => {
+				self.same_size(other);
+				// the least partial sums are concave again
+				let length = self.abs().max(other.abs());
+				let sums : Vec<usize> = self.partial_sums(length).into_iter().zip(other.partial_sums(length)).map(|(s, o)| s.min(o)).collect();
+				Diagram::from_partial_sums(&sums)
+			}
+		}
+	}
+
+	/// the least upper bound in `order`, panics for the dominance order of diagrams of different sizes
+	pub fn join(&self, other : &Diagram, order : DiagramOrder) -> Diagram {
+		match order {
+			DiagramOrder::Containment => Diagram::from(self.iter().zip(other.iter()).take(self.abs().max(other.abs())).map(|(s, o)| *s.max(o)).collect()),
+			// the conjugation reverses the dominance order
+			DiagramOrder::Dominance => {
+				self.same_size(other);
+				self.conjugate().meet(&other.conjugate(), order).conjugate()
+			}
+		}
+	}
+
+	/// the diagrams covering `self` in `order`
+	pub fn upper_covers(&self, order : DiagramOrder) -> Vec<Diagram> {
+		let rows : Vec<usize> = self.iter().take(self.abs() + 1).cloned().collect();
+		match order {
+			// add a box at the end of a row
+			DiagramOrder::Containment => (0..rows.len()).filter(|i| *i == 0 || rows[i - 1] > rows[*i]).map(|i| {
+				let mut rows = rows.clone();
+				rows[i] += 1;
+				Diagram::from(rows)
+			}).collect(),
+			// raise a box from the row `j` to the row `i`, next to each other or between rows of the same length
+			DiagramOrder::Dominance => {
+				let mut covers = Vec::new();
+				for i in 0..self.abs() {
+					for j in i + 1..self.abs() {
+						if j == i + 1 || rows[i] == rows[j] {
+							let mut rows = rows.clone();
+							rows[i] += 1;
+							rows[j] -= 1;
+							covers.extend(Diagram::try_from(rows));
+						}
+					}
+				}
+				covers
+			}
+		}
+	}
+
+	/// the diagrams covered by `self` in `order`
+	pub fn lower_covers(&self, order : DiagramOrder) -> Vec<Diagram> {
+		let rows : Vec<usize> = self.iter().take(self.abs() + 1).cloned().collect();
+		match order {
+			// remove a corner
+			DiagramOrder::Containment => (0..self.abs()).filter(|i| rows[*i] > rows[i + 1]).map(|i| {
+				let mut rows = rows.clone();
+				rows[i] -= 1;
+				Diagram::from(rows)
+			}).collect(),
+			// lower a box from the row `i` to the row `j`, next to each other or between rows differing by two boxes
+			DiagramOrder::Dominance => {
+				let mut covers = Vec::new();
+				for i in 0..self.abs() {
+					for j in i + 1..rows.len() {
+						if j == i + 1 || rows[i] == rows[j] + 2 {
+							let mut rows = rows.clone();
+							rows[i] -= 1;
+							rows[j] += 1;
+							covers.extend(Diagram::try_from(rows));
+						}
+					}
+				}
+				covers
+			}
+		}
+	}
+
+	/// the interval `[self, upper]` in `order`
+	pub fn interval_in(&self, upper : &Diagram, order : DiagramOrder) -> Poset<Diagram> {
+		match order {
+			DiagramOrder::Containment => self.interval(upper),
+			DiagramOrder::Dominance => {
+				if !upper.dominates(self) {
+					return Poset::new(Vec::new(), |_, _| true);
+				}
+				// the diagrams in between have at most `upper_1` columns and `self.abs()` rows
+				let constraints = PartitionConstraints { max_part : Some(upper[0]), max_length : Some(self.abs()), ..Default::default() };
+				let diagrams = Diagram::partitions_with(self.n(), constraints, PartitionOrder::Lex)
+					.filter(|d| d.dominates(self) && upper.dominates(d))
+					.collect();
+				Poset::new(diagrams, |a, b| b.dominates(a))
+			}
+		}
+	}
+}
+#[test] fn dominance() {
+	let (a, b) = (Diagram::from(vec![3, 1, 1, 1]), Diagram::from(vec![2, 2, 2]));
+	assert_eq!(a.dominance_cmp(&b), None);
+	assert_eq!(a.lex_cmp(&b), Ordering::Greater);
+	assert_eq!(a.meet(&b, DiagramOrder::Dominance), Diagram::from(vec![2, 2, 1, 1]));
+	assert_eq!(a.join(&b, DiagramOrder::Dominance), Diagram::from(vec![3, 2, 1]));
+	assert_eq!(a.meet(&b, DiagramOrder::Containment), Diagram::from(vec![2, 1, 1]));
+	assert_eq!(a.join(&b, DiagramOrder::Containment), Diagram::from(vec![3, 2, 2, 1]));
+	assert_eq!(Diagram::from(vec![4, 2]).dominance_cmp(&Diagram::from(vec![3, 3])), Some(Ordering::Greater));
+	assert_eq!(Diagram::from(vec![4, 2]).dominance_cmp(&Diagram::from(vec![3, 2])), None);
+
+	// the lexicographic order is a linear extension of the dominance order
+	let all : Vec<Diagram> = Diagram::partitions(8).collect();
+	for x in &all {
+		for y in &all {
+			if x.dominates(y) {
+				assert_ne!(x.lex_cmp(y), Ordering::Less);
+			}
+			let (meet, join) = (x.meet(y, DiagramOrder::Dominance), x.join(y, DiagramOrder::Dominance));
+			assert!(x.dominates(&meet) && y.dominates(&meet) && join.dominates(x) && join.dominates(y));
+			assert!(all.iter().filter(|z| x.dominates(z) && y.dominates(z)).all(|z| meet.dominates(z)));
+			assert!(all.iter().filter(|z| z.dominates(x) && z.dominates(y)).all(|z| z.dominates(&join)));
+		}
+	}
+
+	// the covers are the covering relations of the whole poset, for both orders
+	let dominance = Diagram::from(vec![1; 8]).interval_in(&Diagram::from(vec![8]), DiagramOrder::Dominance);
+	assert_eq!(dominance.len(), 22);
+	let mut covers : Vec<(Diagram, Diagram)> = dominance.covers().into_iter()
+		.map(|(i, j)| (dominance.elements()[i].clone(), dominance.elements()[j].clone())).collect();
+	let mut upper : Vec<(Diagram, Diagram)> = all.iter().flat_map(|x| x.upper_covers(DiagramOrder::Dominance).into_iter().map(move |y| (x.clone(), y))).collect();
+	let mut lower : Vec<(Diagram, Diagram)> = all.iter().flat_map(|y| y.lower_covers(DiagramOrder::Dominance).into_iter().map(move |x| (x, y.clone()))).collect();
+	for v in [&mut covers, &mut upper, &mut lower] {
+		v.sort_by(|(a, b), (c, d)| a.lex_cmp(c).then(b.lex_cmp(d)));
+	}
+	assert_eq!(covers, upper);
+	assert_eq!(covers, lower);
+
+	let diagram = Diagram::from(vec![2, 2, 1]);
+	assert_eq!(diagram.upper_covers(DiagramOrder::Containment), vec![Diagram::from(vec![3, 2, 1]), Diagram::from(vec![2, 2, 2]), Diagram::from(vec![2, 2, 1, 1])]);
+	assert_eq!(diagram.lower_covers(DiagramOrder::Containment), vec![Diagram::from(vec![2, 1, 1]), Diagram::from(vec![2, 2])]);
+	let interval = Diagram::from(vec![2, 2, 1, 1]).interval_in(&Diagram::from(vec![4, 2]), DiagramOrder::Dominance);
+	// (2, 2, 1, 1), (2, 2, 2), (3, 1, 1, 1), (3, 2, 1), (4, 1, 1), (3, 3), (4, 2)
+	assert_eq!(interval.len(), 7);
+	assert_eq!(Diagram::from(vec![4, 2]).interval_in(&Diagram::from(vec![3, 3]), DiagramOrder::Dominance).len(), 0);
+	assert_eq!(Diagram::from(vec![1]).interval_in(&Diagram::from(vec![2, 2]), DiagramOrder::Containment).len(), 5);
+}
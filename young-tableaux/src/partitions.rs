@@ -0,0 +1,294 @@
+//! enumerating and counting the partitions of `n`, the Young diagrams with `n` boxes
+
+use general::algebra::num::ZZ;
+
+use crate::Diagram;
+
+/// the restrictions on the parts of a partition, nothing is restricted by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PartitionConstraints {
+	pub max_part : Option<usize>,
+	pub max_length : Option<usize>,
+	pub distinct : bool,
+	pub odd : bool,
+}
+impl PartitionConstraints {
+	/// the partitions fitting in the `a × b` box, with at most `a` parts, each at most `b`
+	pub fn in_box(a : usize, b : usize) -> PartitionConstraints {
+		PartitionConstraints { max_part : Some(b), max_length : Some(a), ..Default::default() }
+	}
+
+	fn allows(&self, part : usize) -> bool {
+		!self.odd || part % 2 == 1
+	}
+
+	/// the bound of the parts after `part`
+	fn next_bound(&self, part : usize) -> usize {
+		if self.distinct { part - 1 } else { part }
+	}
+
+	/// whether `rem` is a sum of at most `slots` allowed parts, each at most `bound`
+	fn feasible(&self, rem : usize, bound : usize, slots : usize) -> bool {
+		if rem == 0 {
+			return true;
+		}
+		let bound = if self.odd && bound.is_multiple_of(2) { bound.saturating_sub(1) } else { bound };
+		if bound == 0 || slots == 0 {
+			return false;
+		}
+		match (self.distinct, self.odd) {
+			(false, false) => rem <= bound * slots,
+			(false, true) => {
+				// `k` odd parts sum to `k` mod 2, between `k` and `k * bound`
+				let mut k = rem.div_ceil(bound);
+				if k % 2 != rem % 2 {
+					k += 1;
+				}
+				k <= slots && k <= rem
+			}
+			// the sums of `k` distinct parts fill the interval between `1 + 2 + ... + k` and `bound + ... + (bound - k + 1)`
+			(true, false) => (1..=slots.min(bound)).take_while(|k| k * (k + 1) / 2 <= rem)
+				.any(|k| rem <= k * bound - k * (k - 1) / 2),
+			// the sums of `k` distinct odd parts are `k` mod 2, between `1 + 3 + ... = k^2` and `bound + (bound - 2) + ...`
+			(true, true) => (1..=slots.min(bound.div_ceil(2))).take_while(|k| k * k <= rem)
+				.any(|k| k % 2 == rem % 2 && rem <= k * bound - k * (k - 1)),
+		}
+	}
+}
+
+/// the order of the parts as sequences, `Lex` starts from `1 + 1 + ... + 1`, `RevLex` starts from `n`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartitionOrder {
+	#[default]
+	Lex,
+	RevLex,
+}
+
+/// a lazy iterator over the partitions of `n` satisfying the constraints, see `Diagram::partitions_with`
+#[derive(Debug, Clone)]
+pub struct Partitions {
+	n : usize,
+	constraints : PartitionConstraints,
+	order : PartitionOrder,
+	/// the last partition returned, `None` before the first one
+	parts : Option<Vec<usize>>,
+	exhausted : bool,
+}
+impl Partitions {
+	/// the bound of the part after `prefix`
+	fn bound(&self, prefix : &[usize]) -> usize {
+		let rem = self.n - prefix.iter().sum::<usize>();
+		let bound = match prefix.last() {
+			Some(&last) => self.constraints.next_bound(last),
+			None => self.constraints.max_part.unwrap_or(self.n),
+		};
+		bound.min(rem)
+	}
+
+	/// the number of parts still allowed after `used` parts
+	fn slots(&self, used : usize) -> usize {
+		self.constraints.max_length.map_or(self.n, |l| l.saturating_sub(used))
+	}
+
+	/// whether `part` may follow `prefix`, so that the rest can still be completed
+	fn extends(&self, prefix : &[usize], part : usize) -> bool {
+		let rem = self.n - prefix.iter().sum::<usize>();
+		self.constraints.allows(part) && self.constraints.feasible(rem - part, self.constraints.next_bound(part), self.slots(prefix.len() + 1))
+	}
+
+	/// complete `parts` by the least parts in `Lex`, or the greatest ones in `RevLex`
+	fn fill(&self, parts : &mut Vec<usize>) {
+		while parts.iter().sum::<usize>() < self.n {
+			let bound = self.bound(parts);
+			let part = match self.order {
+				PartitionOrder::Lex => (1..=bound).find(|p| self.extends(parts, *p)),
+				PartitionOrder::RevLex => (1..=bound).rev().find(|p| self.extends(parts, *p)),
+			};
+			parts.push(part.expect("the rest has been checked to be feasible"));
+		}
+	}
+}
+impl Iterator for Partitions {
+	type Item = Diagram;
+	fn next(&mut self) -> Option<Diagram> {
+		if self.exhausted {
+			return None;
+		}
+		let next = match self.parts.take() {
+			None => {
+				let c = &self.constraints;
+				if c.feasible(self.n, c.max_part.unwrap_or(self.n), self.slots(0)) {
+					let mut parts = Vec::new();
+					self.fill(&mut parts);
+					Some(parts)
+				} else {
+					None
+				}
+			}
+			Some(mut parts) => {
+				// change the rightmost part which can be changed, and complete the rest
+				let mut next = None;
+				for i in (0..parts.len()).rev() {
+					let (prefix, current) = (&parts[..i], parts[i]);
+					let part = match self.order {
+						PartitionOrder::Lex => (current + 1..=self.bound(prefix)).find(|p| self.extends(prefix, *p)),
+						PartitionOrder::RevLex => (1..current).rev().find(|p| self.extends(prefix, *p)),
+					};
+					if let Some(part) = part {
+						parts.truncate(i);
+						parts.push(part);
+						self.fill(&mut parts);
+						next = Some(parts);
+						break;
+					}
+				}
+				next
+			}
+		};
+		match next {
+			Some(parts) => {
+				self.parts = Some(parts.clone());
+				Some(Diagram::from(parts))
+			}
+			None => {
+				self.exhausted = true;
+				None
+			}
+		}
+	}
+}
+
+impl Diagram {
+	/// all the partitions of `n`, in lexicographic order
+	pub fn partitions(n : usize) -> Partitions {
+		Diagram::partitions_with(n, PartitionConstraints::default(), PartitionOrder::Lex)
+	}
+
+	/// the partitions of `n` satisfying the constraints, in the given order
+	pub fn partitions_with(n : usize, constraints : PartitionConstraints, order : PartitionOrder) -> Partitions {
+		Partitions { n, constraints, order, parts : None, exhausted : false }
+	}
+
+	/// `p(n)`, by Euler's pentagonal number theorem, `None` when it overflows `ZZ`, i.e. for `n > 1437`
+	pub fn partition_number(n : usize) -> Option<ZZ> {
+		let mut p : Vec<i128> = vec![1];
+		for m in 1..=n {
+			// `p(m - k(3k - 1)/2)` and `p(m - k(3k + 1)/2)` are added for odd `k` and subtracted for even `k`
+			let (mut added, mut subtracted) = (Vec::new(), Vec::new());
+			for k in 1.. {
+				let pentagonal = k * (3 * k - 1) / 2;
+				if pentagonal > m {
+					break;
+				}
+				let terms = if k % 2 == 1 { &mut added } else { &mut subtracted };
+				terms.push(p[m - pentagonal]);
+				if pentagonal + k <= m {
+					terms.push(p[m - pentagonal - k]);
+				}
+			}
+			// subtracting while the sum is positive keeps it between `-p(m - 5)` and `max(p(m - 1), p(m))`
+			let (mut added, mut subtracted) = (added.into_iter(), subtracted.into_iter().peekable());
+			let mut sum : i128 = 0;
+			loop {
+				if sum >= 0 && subtracted.peek().is_some() {
+					sum -= subtracted.next().unwrap();
+				} else if let Some(term) = added.next() {
+					sum = sum.checked_add(term)?;
+				} else if let Some(term) = subtracted.next() {
+					sum -= term;
+				} else {
+					break;
+				}
+			}
+			p.push(sum);
+		}
+		Some(ZZ::from(p[n]))
+	}
+
+	/// the number of partitions of `n` satisfying the constraints, without enumerating them,
+	/// `None` when it overflows `ZZ`, where every count on the way is at most `p(n)`, so this never happens for `n <= 1437`
+	pub fn count_partitions(n : usize, constraints : &PartitionConstraints) -> Option<ZZ> {
+		let parts : Vec<usize> = (1..=constraints.max_part.unwrap_or(n).min(n)).filter(|v| constraints.allows(*v)).collect();
+		match constraints.max_length {
+			None => {
+				// `ways[s]`: the partitions of `s` into the parts considered so far
+				let mut ways : Vec<i128> = vec![0; n + 1];
+				ways[0] = 1;
+				for &v in &parts {
+					if constraints.distinct {
+						for s in (v..=n).rev() {
+							ways[s] = ways[s].checked_add(ways[s - v])?;
+						}
+					} else {
+						for s in v..=n {
+							ways[s] = ways[s].checked_add(ways[s - v])?;
+						}
+					}
+				}
+				Some(ZZ::from(ways[n]))
+			}
+			Some(max_length) => {
+				// `ways[k][s]`: the partitions of `s` into `k` of the parts considered so far
+				let max_length = max_length.min(n);
+				let mut ways : Vec<Vec<i128>> = vec![vec![0; n + 1]; max_length + 1];
+				ways[0][0] = 1;
+				for &v in &parts {
+					// with `k` increasing a part may be used again, with `k` decreasing it may not
+					let ks : Vec<usize> = if constraints.distinct { (1..=max_length).rev().collect() } else { (1..=max_length).collect() };
+					for k in ks {
+						for s in v..=n {
+							ways[k][s] = ways[k][s].checked_add(ways[k - 1][s - v])?;
+						}
+					}
+				}
+				ways.iter().try_fold(0i128, |acc, w| acc.checked_add(w[n])).map(ZZ::from)
+			}
+		}
+	}
+}
+#[test] fn partitions() {
+	let lex : Vec<Diagram> = Diagram::partitions(5).collect();
+	assert_eq!(lex, [vec![1,1,1,1,1], vec![2,1,1,1], vec![2,2,1], vec![3,1,1], vec![3,2], vec![4,1], vec![5]].map(Diagram::from));
+	let mut revlex : Vec<Diagram> = Diagram::partitions_with(5, PartitionConstraints::default(), PartitionOrder::RevLex).collect();
+	revlex.reverse();
+	assert_eq!(lex, revlex);
+	assert_eq!(Diagram::partitions(0).collect::<Vec<_>>(), vec![Diagram::from(vec![])]);
+
+	assert_eq!(Diagram::partition_number(100), Some(ZZ::from(190569292)));
+	assert_eq!(Diagram::partition_number(1000), Some(ZZ::from(24061467864032622473692149727991)));
+	// the largest `p(n)` in `ZZ`
+	assert_eq!(Diagram::partition_number(1437), Some(ZZ::from(168434321304033467550147269349447360294)));
+	assert_eq!(Diagram::partition_number(1438), None);
+	assert_eq!(Diagram::count_partitions(1437, &PartitionConstraints::default()), Diagram::partition_number(1437));
+	assert_eq!(Diagram::count_partitions(1438, &PartitionConstraints::default()), None);
+	assert_eq!(Diagram::partitions(30).count(), 5604);
+
+	// partitions in the `3 × 4` box, counted by the binomial coefficient `C(7, 3)`
+	let in_box = PartitionConstraints::in_box(3, 4);
+	assert_eq!((0..=12).map(|n| Diagram::partitions_with(n, in_box, PartitionOrder::Lex).count()).sum::<usize>(), 35);
+
+	for n in 0..=16 {
+		for (max_part, max_length, distinct, odd) in [(None, None, true, false), (None, None, false, true), (Some(4), None, false, false), (None, Some(3), true, true), (Some(7), Some(4), false, true), (Some(6), Some(3), true, false)] {
+			let c = PartitionConstraints { max_part, max_length, distinct, odd };
+			for order in [PartitionOrder::Lex, PartitionOrder::RevLex] {
+				let all : Vec<Diagram> = Diagram::partitions_with(n, c, order).collect();
+				assert!(all.iter().all(|d| {
+					let parts : Vec<usize> = d.iter_finite().cloned().collect();
+					parts.iter().all(|p| max_part.is_none_or(|m| *p <= m) && (!odd || p % 2 == 1))
+						&& max_length.is_none_or(|m| parts.len() <= m)
+						&& (!distinct || parts.windows(2).all(|w| w[0] > w[1]))
+				}));
+				assert!(all.windows(2).all(|w| {
+					let (a, b) : (Vec<usize>, Vec<usize>) = (w[0].iter_finite().cloned().collect(), w[1].iter_finite().cloned().collect());
+					if order == PartitionOrder::Lex { a < b } else { a > b }
+				}));
+				assert_eq!(Some(ZZ::from(all.len() as i128)), Diagram::count_partitions(n, &c));
+			}
+		}
+		// Euler: as many partitions into distinct parts as into odd parts
+		let distinct = PartitionConstraints { distinct : true, ..Default::default() };
+		let odd = PartitionConstraints { odd : true, ..Default::default() };
+		assert_eq!(Diagram::count_partitions(n, &distinct), Diagram::count_partitions(n, &odd));
+		assert_eq!(Diagram::count_partitions(n, &PartitionConstraints::default()), Diagram::partition_number(n));
+	}
+}
@@ -0,0 +1,151 @@
+//! the Knuth equivalence of words, and the plactic classes, the words whose insertion tableau is a given tableau
+
+use crate::{Word, Tableau, StandardTableau};
+
+impl Word {
+	/// the words obtained by one elementary Knuth transformation,
+	/// `y z x <-> y x z` for `x < y <= z` and `x z y <-> z x y` for `x <= y < z`, on three consecutive letters
+	pub fn knuth_moves(&self) -> Vec<Word> {
+		let w = &self.0;
+		let mut moves : Vec<Word> = Vec::new();
+		for p in 0..w.len().saturating_sub(2) {
+			let (a, b, c) = (w[p], w[p + 1], w[p + 2]);
+			// `y z x` or `y x z`, the last two letters are swapped
+			let first = (c < a && a <= b) || (b < a && a <= c);
+			// `x z y` or `z x y`, the first two letters are swapped
+			let second = (a <= c && c < b) || (b <= c && c < a);
+			for (possible, i) in [(first, p + 1), (second, p)] {
+				if possible {
+					let mut v = w.clone();
+					v.swap(i, i + 1);
+					if !moves.contains(&Word(v.clone())) {
+						moves.push(Word(v));
+					}
+				}
+			}
+		}
+		moves
+	}
+
+	/// `w ≡ w'`, i.e. they have the same insertion tableau
+	pub fn knuth_equivalent(&self, other : &Word) -> bool {
+		self.to_tableau() == other.to_tableau()
+	}
+
+	/// the words from `self` to the reading word of `self.to_tableau()`, one elementary Knuth transformation after another,
+	/// following the row insertions, where inserting `x` into a row `u_1 ... u_p` bumping `u_i` is
+	/// `u_1 ... u_p x ≡ u_1 ... u_i x u_{i + 1} ... u_p ≡ u_i u_1 ... u_{i - 1} x u_{i + 1} ... u_p`
+	fn knuth_chain_to_tableau(&self) -> Vec<Word> {
+		let mut word = self.0.clone();
+		let mut chain = vec![self.clone()];
+		// the rows of the insertion tableau of the letters inserted so far, whose reading word starts `word`
+		let mut rows : Vec<Vec<usize>> = Vec::new();
+		for k in 0..word.len() {
+			let mut x = word[k];
+			// `x` at the position `pos`, right after the row `j`
+			let mut pos = k;
+			let mut j = 0;
+			loop {
+				if j == rows.len() {
+					rows.push(vec![x]);
+					break;
+				}
+				let start = pos - rows[j].len();
+				let Some(i) = rows[j].iter().position(|u| *u > x) else {
+					rows[j].push(x);
+					break;
+				};
+				// `x` moves to the left, past `u_p, ..., u_{i + 1}`
+				for q in (start + i + 1..pos).rev() {
+					word.swap(q, q + 1);
+					chain.push(Word(word.clone()));
+				}
+				// then `u_i` moves to the left, past `u_{i - 1}, ..., u_1`
+				for q in (start..start + i).rev() {
+					word.swap(q, q + 1);
+					chain.push(Word(word.clone()));
+				}
+				x = std::mem::replace(&mut rows[j][i], x);
+				pos = start;
+				j += 1;
+			}
+		}
+		chain
+	}
+
+	/// the words from `self` to `other`, one elementary Knuth transformation after another, if they are Knuth equivalent
+	pub fn knuth_chain(&self, other : &Word) -> Option<Vec<Word>> {
+		if !self.knuth_equivalent(other) {
+			return None;
+		}
+		let mut chain = self.knuth_chain_to_tableau();
+		let mut back = other.knuth_chain_to_tableau();
+		// both end with the reading word of the common tableau
+		back.pop();
+		chain.extend(back.into_iter().rev());
+		Some(chain)
+	}
+}
+
+impl Tableau {
+	/// the plactic class of `self`, the words whose insertion tableau is `self`, lazily,
+	/// one for each standard tableau `Q` of the same shape, by the inverse of the insertion with the recording tableau `Q`
+	pub fn plactic_class(&self) -> impl Iterator<Item = Word> + '_ {
+		let n = self.shape().n();
+		StandardTableau::of_shape(&self.shape()).map(move |q| {
+			let mut p = self.clone();
+			let mut letters : Vec<usize> = (1..=n).rev().map(|k| {
+				let row = q.iter_finite().position(|row| row.contains(&k)).expect("a standard tableau contains each of 1..=n");
+				p.reverse_bumping(row)
+			}).collect();
+			letters.reverse();
+			Word(letters)
+		})
+	}
+}
+#[test] fn knuth_equivalence() {
+	assert_eq!(Word(vec![2, 3, 1]).knuth_moves(), vec![Word(vec![2, 1, 3])]);
+	assert_eq!(Word(vec![1, 3, 2]).knuth_moves(), vec![Word(vec![3, 1, 2])]);
+	assert!(Word(vec![1, 2, 3]).knuth_moves().is_empty());
+	assert!(Word(vec![2, 1, 3]).knuth_equivalent(&Word(vec![2, 3, 1])));
+	assert!(!Word(vec![2, 1, 3]).knuth_equivalent(&Word(vec![1, 3, 2])));
+
+	let lhs = Word(vec![1, 2, 3, 4, 2, 3, 4, 9, 2, 3]);
+	let rhs = Word(vec![1, 2, 3, 42, 343, 464, 334, 33]);
+	assert!((lhs.clone() * rhs.clone()).knuth_equivalent(&(lhs.to_tableau().to_word() * rhs.to_tableau().to_word())));
+
+	for w in [vec![3, 1, 2, 2, 1, 3, 1], vec![1, 4, 2, 5, 6, 3], vec![5, 4, 3, 2, 1]] {
+		let w = Word(w);
+		let v = w.to_tableau().plactic_class().last().unwrap();
+		let chain = w.knuth_chain(&v).unwrap();
+		assert_eq!((chain.first(), chain.last()), (Some(&w), Some(&v)));
+		assert!(chain.windows(2).all(|pair| pair[0].knuth_moves().contains(&pair[1]) && pair[1].knuth_moves().contains(&pair[0])));
+		assert_eq!(w.knuth_chain_to_tableau().last(), Some(&w.to_tableau().to_word()));
+	}
+	assert_eq!(Word(vec![1, 3, 2]).knuth_chain(&Word(vec![2, 1, 3])), None);
+
+	let tableau = Tableau::from(vec![vec![1, 1, 2], vec![2, 3]]);
+	let class : Vec<Word> = tableau.plactic_class().collect();
+	assert_eq!(class.len(), 5);
+	assert!(class.contains(&tableau.to_word()));
+	assert!(class.iter().all(|w| w.to_tableau() == tableau));
+	// the classes are closed under the elementary Knuth transformations, and they are connected
+	for w in &class {
+		assert!(w.knuth_moves().iter().all(|v| class.contains(v)));
+	}
+	let tableau = Tableau::from(vec![vec![1, 2, 4], vec![3, 5], vec![6]]);
+	let class : Vec<Word> = tableau.plactic_class().collect();
+	assert_eq!(class.len(), 16);
+	let mut reached = vec![tableau.to_word()];
+	let mut k = 0;
+	while k < reached.len() {
+		for v in reached[k].knuth_moves() {
+			if !reached.contains(&v) {
+				reached.push(v);
+			}
+		}
+		k += 1;
+	}
+	assert_eq!(reached.len(), class.len());
+	assert!(class.iter().all(|w| reached.contains(w)));
+}
@@ -0,0 +1,79 @@
+//! the Schur polynomials `s_λ(x_1, ..., x_m)`
+
+use std::collections::HashMap;
+
+use general::algebra::{AddGroup, IntegralDomain, MultiPolynomial, MonomialFormat, Var};
+use general::algebra::num::ZZ;
+
+use crate::{Diagram, Tableau};
+use crate::symmetric::signed_permutations;
+
+/// `c x_1^e_1 ... x_m^e_m`, where `x_i = vars[i]`
+fn monomial(vars : &[Var], e : &[usize], c : ZZ) -> MultiPolynomial<ZZ> {
+	MultiPolynomial::from_format(&MonomialFormat::from(vars.iter().cloned().zip(e.iter().cloned()).collect()), c)
+}
+
+/// the alternant `det(x_j^{α_i}) = Σ sgn(σ) x_σ(1)^α_1 ... x_σ(m)^α_m`, where `m = vars.len() = alpha.len()`
+fn alternant(vars : &[Var], alpha : &[usize]) -> MultiPolynomial<ZZ> {
+	signed_permutations(alpha.len()).into_iter().fold(MultiPolynomial::zero(), |acc, (sigma, sign)| {
+		let mut e = vec![0; alpha.len()];
+		for (i, j) in sigma.into_iter().enumerate() {
+			e[j] = alpha[i];
+		}
+		acc + monomial(vars, &e, ZZ::from(sign))
+	})
+}
+
+impl Diagram {
+	/// `s_λ(x_1, ..., x_m) = Σ x^{content(T)}`, over the semistandard tableaux `T` of shape `λ` with entries in `1..=m`, where `x_i = vars[i - 1]`
+	pub fn schur_polynomial(&self, vars : &[Var]) -> MultiPolynomial<ZZ> {
+		let mut contents : HashMap<Vec<usize>, i128> = HashMap::new();
+		for tableau in Tableau::semistandard(self, vars.len()) {
+			*contents.entry(tableau.content_1()).or_default() += 1;
+		}
+		contents.into_iter().fold(MultiPolynomial::zero(), |acc, (e, c)| acc + monomial(vars, &e, ZZ::from(c)))
+	}
+
+	/// `s_λ(x_1, ..., x_m)` as the bialternant `a_{λ + δ} / a_δ`, where `δ = (m - 1, ..., 1, 0)`
+	pub fn schur_bialternant(&self, vars : &[Var]) -> MultiPolynomial<ZZ> {
+		let m = vars.len();
+		if self.abs() > m {
+			return MultiPolynomial::zero();
+		}
+		let delta : Vec<usize> = (0..m).rev().collect();
+		let shifted : Vec<usize> = delta.iter().enumerate().map(|(i, d)| self[i] + d).collect();
+		alternant(vars, &shifted).exact_div(&alternant(vars, &delta)).expect("the Vandermonde determinant divides every alternant")
+	}
+}
+#[test] fn schur() {
+	use general::algebra::{VarTable, Ring};
+	let mut vt = VarTable::new();
+	for name in ["x", "y", "z", "w"] {
+		vt.push(name.into());
+	}
+	let vars : Vec<Var> = (0..4).map(|i| Var::from_index(i, &vt)).collect();
+	let (x, y, z) = (MultiPolynomial::<ZZ>::from_var(vars[0]), MultiPolynomial::<ZZ>::from_var(vars[1]), MultiPolynomial::<ZZ>::from_var(vars[2]));
+
+	// s_{(2, 1)}(x, y, z) = (x + y + z)(xy + yz + zx) - xyz
+	let e1 = x.clone() + y.clone() + z.clone();
+	let e2 = x.clone() * y.clone() + y.clone() * z.clone() + z.clone() * x.clone();
+	let e3 = x.clone() * y.clone() * z.clone();
+	let s21 = Diagram::from(vec![2, 1]).schur_polynomial(&vars[..3]);
+	assert_eq!(s21, e1.clone() * e2.clone() - e3.clone());
+	assert_eq!(s21.coef(&vec![1, 1, 1]), ZZ::from(2));
+	// the complete and elementary symmetric polynomials
+	assert_eq!(Diagram::from(vec![1, 1, 1]).schur_polynomial(&vars[..3]), e3);
+	assert_eq!(Diagram::from(vec![2]).schur_polynomial(&vars[..3]), e1.pow(2) - e2);
+	assert_eq!(Diagram::from(vec![1, 1, 1, 1]).schur_polynomial(&vars[..3]), MultiPolynomial::zero());
+	assert_eq!(Diagram::new().schur_polynomial(&vars[..3]), MultiPolynomial::one());
+
+	for shape in [vec![2, 1], vec![3, 1, 1], vec![2, 2], vec![4, 2, 1], vec![1, 1, 1, 1], vec![3, 3, 2]] {
+		let diagram = Diagram::from(shape);
+		for m in 1..=4 {
+			let s = diagram.schur_polynomial(&vars[..m]);
+			assert_eq!(s, diagram.schur_bialternant(&vars[..m]));
+			let ones : HashMap<Var, ZZ> = vars[..m].iter().map(|v| (*v, ZZ::one())).collect();
+			assert_eq!(s.eval(&ones), diagram.count_semistandard_tableaux(m));
+		}
+	}
+}
@@ -0,0 +1,478 @@
+//! the ring of symmetric functions, with the bases `m`, `e`, `h`, `p` and `s` indexed by partitions
+
+use std::collections::{BTreeMap, HashMap};
+use std::ops::{Add, Mul, Neg, Sub};
+
+use alias::BasicObject;
+use general::algebra::{AddGroup, Ring, IntegralDomain, ScaMul, Module, Algebra, MultiPolynomial, MonomialFormat, Var};
+use general::algebra::num::ZZ;
+
+use crate::Diagram;
+
+/// `(σ(0), ..., σ(n - 1))` for all the permutations `σ` of `0..n`, together with their signs
+pub(crate) fn signed_permutations(n : usize) -> Vec<(Vec<usize>, i128)> {
+	fn choose(n : usize, sigma : &mut Vec<usize>, sign : i128, all : &mut Vec<(Vec<usize>, i128)>) {
+		if sigma.len() == n {
+			all.push((sigma.clone(), sign));
+			return;
+		}
+		for j in 0..n {
+			if !sigma.contains(&j) {
+				// the images chosen before and greater than `j` are the new inversions
+				let inversions = sigma.iter().filter(|s| **s > j).count();
+				sigma.push(j);
+				choose(n, sigma, if inversions % 2 == 0 { sign } else { -sign }, all);
+				sigma.pop();
+			}
+		}
+	}
+	let mut all = Vec::new();
+	choose(n, &mut Vec::new(), 1, &mut all);
+	all
+}
+
+/// `n` in any ring
+fn integer<R : Ring>(n : i128) -> R {
+	let r = R::one().times(n.unsigned_abs() as usize);
+	if n < 0 { -r } else { r }
+}
+
+fn parts(diagram : &Diagram) -> Vec<usize> {
+	diagram.iter_finite().cloned().collect()
+}
+
+fn partitions(n : usize) -> Vec<Vec<usize>> {
+	Diagram::partitions(n).map(|d| parts(&d)).collect()
+}
+
+fn conjugate(shape : &[usize]) -> Vec<usize> {
+	(0..shape.first().cloned().unwrap_or(0)).map(|i| shape.iter().take_while(|r| **r > i).count()).collect()
+}
+
+/// `z_λ = Π i^{m_i} m_i!`, where `m_i` parts of `λ` are `i`, the order of the centralizer of a permutation of cycle type `λ`
+fn z(shape : &[usize]) -> i128 {
+	let mut multiplicities : HashMap<usize, i128> = HashMap::new();
+	for part in shape {
+		*multiplicities.entry(*part).or_default() += 1;
+	}
+	multiplicities.into_iter().map(|(i, m)| (i as i128).pow(m as u32) * (1..=m).product::<i128>()).product()
+}
+
+/// the Murnaghan–Nakayama rule, removing the border strips of the last part of the cycle type,
+/// as the beta numbers `λ_i + l - 1 - i` lowered by the length of the strip
+fn character(shape : &[usize], cycle_type : &[usize]) -> i128 {
+	let Some((&k, rest)) = cycle_type.split_last() else {
+		return 1;
+	};
+	let l = shape.len();
+	let beta : Vec<usize> = shape.iter().enumerate().map(|(i, part)| part + l - 1 - i).collect();
+	let mut total = 0;
+	for &b in &beta {
+		if b >= k && !beta.contains(&(b - k)) {
+			// the height of the strip
+			let height = beta.iter().filter(|x| b - k < **x && **x < b).count();
+			let mut lowered : Vec<usize> = beta.iter().map(|x| if *x == b { b - k } else { *x }).collect();
+			lowered.sort_by(|x, y| y.cmp(x));
+			let shape : Vec<usize> = lowered.iter().enumerate().map(|(i, x)| x + i + 1 - l).take_while(|part| *part > 0).collect();
+			let chi = character(&shape, rest);
+			total += if height % 2 == 0 { chi } else { -chi };
+		}
+	}
+	total
+}
+
+/// `s_λ = det(h_{λ_i - i + j})`, as the terms `± h_μ`
+fn jacobi_trudi(shape : &[usize]) -> Vec<(Vec<usize>, i128)> {
+	signed_permutations(shape.len()).into_iter().filter_map(|(sigma, sign)| {
+		let mut mu = Vec::new();
+		for (i, j) in sigma.into_iter().enumerate() {
+			let part = (shape[i] + j).checked_sub(i)?;
+			if part > 0 {
+				mu.push(part);
+			}
+		}
+		mu.sort_by(|x, y| y.cmp(x));
+		Some((mu, sign))
+	}).collect()
+}
+
+impl Diagram {
+	/// `χ^λ(μ)`, the irreducible character of the symmetric group `S_n` indexed by `λ = self`, at the permutations of cycle type `μ`
+	pub fn character(&self, cycle_type : &Diagram) -> ZZ {
+		if self.n() != cycle_type.n() {
+			panic!("Diagram: the character of S_{} at a permutation in S_{}", self.n(), cycle_type.n())
+		}
+		ZZ::from(character(&parts(self), &parts(cycle_type)))
+	}
+}
+#[test] fn characters() {
+	let chi = |l : Vec<usize>, m : Vec<usize>| *Diagram::from(l).character(&Diagram::from(m));
+	assert_eq!((chi(vec![2, 1], vec![1, 1, 1]), chi(vec![2, 1], vec![2, 1]), chi(vec![2, 1], vec![3])), (2, 0, -1));
+	assert_eq!(chi(vec![2, 2], vec![2, 2]), 2);
+	assert_eq!(chi(vec![3, 1, 1], vec![5]), 1);
+	for n in 1..=7 {
+		let all : Vec<Diagram> = Diagram::partitions(n).collect();
+		let identity = Diagram::from(vec![1; n]);
+		// the degrees are the numbers of standard tableaux, and the characters are orthonormal
+		for l in &all {
+			assert_eq!(l.character(&identity), l.count_standard_tableaux());
+			for m in &all {
+				let product : i128 = all.iter().map(|c| chi(parts(l), parts(c)) * chi(parts(m), parts(c)) * (1..=n as i128).product::<i128>() / z(&parts(c))).sum();
+				assert_eq!(product, if l == m { (1..=n as i128).product() } else { 0 });
+			}
+		}
+	}
+}
+
+/// the bases of the symmetric functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Basis {
+	/// `m_λ`, the sum of the distinct monomials `x^α` with `α` a permutation of `λ`
+	Monomial,
+	/// `e_λ = e_{λ_1} e_{λ_2} ...`, where `e_k` is the sum of all squarefree monomials of degree `k`
+	Elementary,
+	/// `h_λ = h_{λ_1} h_{λ_2} ...`, where `h_k` is the sum of all monomials of degree `k`
+	Homogeneous,
+	/// `p_λ = p_{λ_1} p_{λ_2} ...`, where `p_k = x_1^k + x_2^k + ...`
+	PowerSum,
+	/// `s_λ`, the sum of `x^{content(T)}` over the semistandard tableaux `T` of shape `λ`
+	Schur,
+}
+
+/// a finite linear combination of the basis elements of `basis`, indexed by partitions,
+/// the coefficients into the power sums should be divisible by `z_λ`, which always holds over `QQ`
+#[derive(Debug, Clone)]
+pub struct SymmetricFunction<R : IntegralDomain> {
+	basis : Basis,
+	terms : BTreeMap<Vec<usize>, R>,
+}
+impl<R : IntegralDomain> SymmetricFunction<R> {
+	/// collect terms, repeated partitions are added up
+	pub fn from_terms(basis : Basis, terms : impl IntoIterator<Item = (Diagram, R)>) -> SymmetricFunction<R> {
+		SymmetricFunction::collect(basis, terms.into_iter().map(|(d, c)| (parts(&d), c)))
+	}
+
+	fn collect(basis : Basis, terms : impl IntoIterator<Item = (Vec<usize>, R)>) -> SymmetricFunction<R> {
+		let mut map : BTreeMap<Vec<usize>, R> = BTreeMap::new();
+		for (shape, c) in terms {
+			let c = match map.remove(&shape) {
+				Some(old) => old + c,
+				None => c,
+			};
+			if !c.is_zero() {
+				map.insert(shape, c);
+			}
+		}
+		SymmetricFunction { basis, terms : map }
+	}
+
+	/// the basis element `b_λ`
+	pub fn from_partition(basis : Basis, shape : &Diagram) -> SymmetricFunction<R> {
+		SymmetricFunction::from_terms(basis, [(shape.clone(), R::one())])
+	}
+	pub fn m(shape : &Diagram) -> SymmetricFunction<R> { SymmetricFunction::from_partition(Basis::Monomial, shape) }
+	pub fn e(shape : &Diagram) -> SymmetricFunction<R> { SymmetricFunction::from_partition(Basis::Elementary, shape) }
+	pub fn h(shape : &Diagram) -> SymmetricFunction<R> { SymmetricFunction::from_partition(Basis::Homogeneous, shape) }
+	pub fn p(shape : &Diagram) -> SymmetricFunction<R> { SymmetricFunction::from_partition(Basis::PowerSum, shape) }
+	pub fn s(shape : &Diagram) -> SymmetricFunction<R> { SymmetricFunction::from_partition(Basis::Schur, shape) }
+
+	pub fn basis(&self) -> Basis {
+		self.basis
+	}
+
+	/// the nonzero coefficients in `self.basis()`
+	pub fn terms(&self) -> impl Iterator<Item = (Diagram, &R)> {
+		self.terms.iter().map(|(shape, c)| (Diagram::from(shape.clone()), c))
+	}
+
+	/// the coefficient of `b_λ` in `self.basis()`
+	pub fn coef(&self, shape : &Diagram) -> R {
+		self.terms.get(&parts(shape)).cloned().unwrap_or_else(R::zero)
+	}
+
+	/// the expansion of `b_λ` in the Schur functions, where `inverse_kostka` keeps the inverse Kostka matrices by degree
+	fn to_schur_terms(basis : Basis, shape : &[usize], inverse_kostka : &mut HashMap<usize, (Vec<Diagram>, Vec<Vec<ZZ>>)>) -> Vec<(Vec<usize>, i128)> {
+		let n = shape.iter().sum();
+		match basis {
+			Basis::Schur => vec![(shape.to_vec(), 1)],
+			// `m = K^{-1} s`
+			Basis::Monomial => {
+				let (all, inverse) = inverse_kostka.entry(n).or_insert_with(|| Diagram::inverse_kostka_matrix(n));
+				let row = all.iter().position(|d| parts(d) == shape).unwrap();
+				all.iter().enumerate().map(|(j, d)| (parts(d), *inverse[row][j])).collect()
+			}
+			// `h_μ = Σ K_{λμ} s_λ`
+			Basis::Homogeneous => partitions(n).into_iter().map(|l| {
+				let k = *Diagram::from(l.clone()).kostka(shape);
+				(l, k)
+			}).collect(),
+			// `e_μ = Σ K_{λ'μ} s_λ`
+			Basis::Elementary => partitions(n).into_iter().map(|l| {
+				let k = *Diagram::from(conjugate(&l)).kostka(shape);
+				(l, k)
+			}).collect(),
+			// `p_μ = Σ χ^λ(μ) s_λ`
+			Basis::PowerSum => partitions(n).into_iter().map(|l| {
+				let chi = character(&l, shape);
+				(l, chi)
+			}).collect(),
+		}
+	}
+
+	/// the expansion of `s_λ` in the bases other than the power sums
+	fn from_schur_terms(basis : Basis, shape : &[usize]) -> Vec<(Vec<usize>, i128)> {
+		match basis {
+			Basis::Schur => vec![(shape.to_vec(), 1)],
+			// `s_λ = Σ K_{λμ} m_μ`
+			Basis::Monomial => {
+				let diagram = Diagram::from(shape.to_vec());
+				partitions(diagram.n()).into_iter().map(|m| (m.clone(), *diagram.kostka(&m))).collect()
+			}
+			Basis::Homogeneous => jacobi_trudi(shape),
+			// `s_λ = det(e_{λ'_i - i + j})`
+			Basis::Elementary => jacobi_trudi(&conjugate(shape)),
+			Basis::PowerSum => unreachable!(),
+		}
+	}
+
+	/// the same symmetric function in another basis
+	pub fn to_basis(&self, basis : Basis) -> SymmetricFunction<R> {
+		self.try_to_basis(basis).expect("SymmetricFunction: the coefficients in the power sums are not in the ring")
+	}
+
+	/// `None` when the coefficients in the power sums are not in the ring, the only basis which may fail
+	fn try_to_basis(&self, basis : Basis) -> Option<SymmetricFunction<R>> {
+		if self.basis == basis {
+			return Some(self.clone());
+		}
+		let mut inverse_kostka = HashMap::new();
+		let schur = SymmetricFunction::collect(Basis::Schur, self.terms.iter().flat_map(|(shape, c)| {
+			SymmetricFunction::<R>::to_schur_terms(self.basis, shape, &mut inverse_kostka).into_iter().map(move |(l, a)| (l, c.clone() * integer(a)))
+		}));
+		match basis {
+			Basis::Schur => Some(schur),
+			// `s_λ = Σ χ^λ(μ) / z_μ p_μ`, where only the sums are divided
+			Basis::PowerSum => {
+				let degrees : Vec<usize> = schur.terms.keys().map(|l| l.iter().sum()).collect();
+				let mut terms = Vec::new();
+				for n in degrees.into_iter().collect::<std::collections::BTreeSet<_>>() {
+					for mu in partitions(n) {
+						let sum = schur.terms.iter().filter(|(l, _)| l.iter().sum::<usize>() == n)
+							.fold(R::zero(), |acc, (l, c)| acc + c.clone() * integer(character(l, &mu)));
+						let c = sum.exact_div(&integer(z(&mu)))?;
+						terms.push((mu, c));
+					}
+				}
+				Some(SymmetricFunction::collect(basis, terms))
+			}
+			_ => Some(SymmetricFunction::collect(basis, schur.terms.iter().flat_map(|(shape, c)| {
+				SymmetricFunction::<R>::from_schur_terms(basis, shape).into_iter().map(move |(l, a)| (l, c.clone() * integer(a)))
+			}))),
+		}
+	}
+
+	/// the involution `ω`, which swaps `e_λ` and `h_λ`, and sends `s_λ` to `s_λ'` and `p_λ` to `(-1)^{|λ| - l(λ)} p_λ`
+	pub fn omega(&self) -> SymmetricFunction<R> {
+		match self.basis {
+			Basis::Elementary => SymmetricFunction { basis : Basis::Homogeneous, terms : self.terms.clone() },
+			Basis::Homogeneous => SymmetricFunction { basis : Basis::Elementary, terms : self.terms.clone() },
+			Basis::Schur => SymmetricFunction::collect(Basis::Schur, self.terms.iter().map(|(l, c)| (conjugate(l), c.clone()))),
+			Basis::PowerSum => SymmetricFunction::collect(Basis::PowerSum, self.terms.iter().map(|(l, c)| {
+				let odd = (l.iter().sum::<usize>() - l.len()) % 2 == 1;
+				(l.clone(), if odd { -c.clone() } else { c.clone() })
+			})),
+			Basis::Monomial => self.to_basis(Basis::Schur).omega().to_basis(Basis::Monomial),
+		}
+	}
+
+	/// the Hall inner product, for which the Schur functions are orthonormal
+	pub fn hall(&self, other : &SymmetricFunction<R>) -> R {
+		let (lhs, rhs) = (self.to_basis(Basis::Schur), other.to_basis(Basis::Schur));
+		lhs.terms.iter().fold(R::zero(), |acc, (l, c)| match rhs.terms.get(l) {
+			Some(d) => acc + c.clone() * d.clone(),
+			None => acc,
+		})
+	}
+
+	/// the symmetric polynomial in `vars`, where `m_λ` vanishes when `λ` has more parts than variables
+	pub fn expand(&self, vars : &[Var]) -> MultiPolynomial<R> {
+		let monomial = self.to_basis(Basis::Monomial);
+		MultiPolynomial::from_terms(monomial.terms.iter().filter(|(l, _)| l.len() <= vars.len()).flat_map(|(l, c)| {
+			// the distinct permutations of `λ` padded by zeros, from the least one on
+			let mut alpha : Vec<usize> = l.iter().cloned().chain(std::iter::repeat(0)).take(vars.len()).collect();
+			alpha.sort();
+			let mut monomials = Vec::new();
+			loop {
+				let e = MultiPolynomial::from_format(&MonomialFormat::from(vars.iter().cloned().zip(alpha.iter().cloned()).collect()), c.clone());
+				monomials.extend(e.iter().map(|(e, c)| (e.clone(), c.clone())));
+				// the next permutation in the lexicographic order
+				let Some(i) = (1..alpha.len()).rev().find(|i| alpha[i - 1] < alpha[*i]) else {
+					break;
+				};
+				let j = (i..alpha.len()).rev().find(|j| alpha[*j] > alpha[i - 1]).unwrap();
+				alpha.swap(i - 1, j);
+				alpha[i..].reverse();
+			}
+			monomials
+		}))
+	}
+}
+impl<R : IntegralDomain> PartialEq for SymmetricFunction<R> {
+	/// in the Schur functions, where every coefficient stays in the ring
+	fn eq(&self, other : &Self) -> bool {
+		if self.basis == other.basis {
+			return self.terms == other.terms;
+		}
+		self.to_basis(Basis::Schur).terms == other.to_basis(Basis::Schur).terms
+	}
+}
+impl<R : IntegralDomain> Eq for SymmetricFunction<R> {}
+impl<R : IntegralDomain> BasicObject for SymmetricFunction<R> {}
+
+impl<R : IntegralDomain> Add for SymmetricFunction<R> {
+	type Output = SymmetricFunction<R>;
+	/// in the basis of `self`, unless it is zero, or the sum is not integral in the power sums,
+	/// in which case it is left in the Schur functions
+	fn add(self, rhs : Self) -> Self::Output {
+		if self.terms.is_empty() {
+			return rhs;
+		}
+		if self.basis == rhs.basis {
+			return SymmetricFunction::collect(self.basis, self.terms.into_iter().chain(rhs.terms));
+		}
+		let (lhs, rhs) = (self.to_basis(Basis::Schur), rhs.to_basis(Basis::Schur));
+		let sum = SymmetricFunction::collect(Basis::Schur, lhs.terms.into_iter().chain(rhs.terms));
+		sum.try_to_basis(self.basis).unwrap_or(sum)
+	}
+}
+impl<R : IntegralDomain> Neg for SymmetricFunction<R> {
+	type Output = SymmetricFunction<R>;
+	fn neg(self) -> Self::Output {
+		SymmetricFunction { basis : self.basis, terms : self.terms.into_iter().map(|(l, c)| (l, -c)).collect() }
+	}
+}
+impl<R : IntegralDomain> Sub for SymmetricFunction<R> {
+	type Output = SymmetricFunction<R>;
+	fn sub(self, rhs : Self) -> Self::Output {
+		self + (-rhs)
+	}
+}
+impl<R : IntegralDomain> Mul for SymmetricFunction<R> {
+	type Output = SymmetricFunction<R>;
+	/// by the union of partitions in a multiplicative basis, in the basis of `self` when it is `e` or `h`,
+	/// in the power sums when both are, and in the Schur functions otherwise, computed in `h`
+	fn mul(self, rhs : Self) -> Self::Output {
+		let basis = match self.basis {
+			Basis::Elementary | Basis::Homogeneous => self.basis,
+			Basis::PowerSum if rhs.basis == Basis::PowerSum => Basis::PowerSum,
+			_ => Basis::Homogeneous,
+		};
+		let (lhs, rhs_in_basis) = (self.to_basis(basis), rhs.to_basis(basis));
+		let product = SymmetricFunction::collect(basis, lhs.terms.iter().flat_map(|(a, c)| rhs_in_basis.terms.iter().map(move |(b, d)| {
+			let mut union : Vec<usize> = a.iter().chain(b).cloned().collect();
+			union.sort_by(|x, y| y.cmp(x));
+			(union, c.clone() * d.clone())
+		})));
+		if basis == self.basis {
+			product
+		} else {
+			product.to_basis(Basis::Schur)
+		}
+	}
+}
+impl<R : IntegralDomain> AddGroup for SymmetricFunction<R> {
+	fn zero() -> Self {
+		SymmetricFunction { basis : Basis::Schur, terms : BTreeMap::new() }
+	}
+}
+impl<R : IntegralDomain> Ring for SymmetricFunction<R> {
+	fn one() -> Self {
+		SymmetricFunction::s(&Diagram::new())
+	}
+}
+impl<R : IntegralDomain> ScaMul<R> for SymmetricFunction<R> {
+	fn sca_mul(self, sca : R) -> Self {
+		SymmetricFunction::collect(self.basis, self.terms.into_iter().map(|(l, c)| (l, sca.clone() * c)))
+	}
+}
+impl<R : IntegralDomain> Module<R> for SymmetricFunction<R> {}
+impl<R : IntegralDomain> Algebra<R> for SymmetricFunction<R> {}
+
+#[test] fn symmetric_functions() {
+	use general::algebra::{VarTable, num::QQ};
+	let d = |v : Vec<usize>| Diagram::from(v);
+	type S = SymmetricFunction<ZZ>;
+	type T = SymmetricFunction<QQ>;
+
+	// s_1 s_1 = s_2 + s_{11}, h_2 = s_2, e_2 = s_{11}, p_2 = s_2 - s_{11}
+	assert_eq!(S::s(&d(vec![1])) * S::s(&d(vec![1])), S::s(&d(vec![2])) + S::s(&d(vec![1, 1])));
+	assert_eq!(S::h(&d(vec![2])), S::s(&d(vec![2])));
+	assert_eq!(S::e(&d(vec![2])), S::s(&d(vec![1, 1])));
+	assert_eq!(S::p(&d(vec![2])), S::s(&d(vec![2])) - S::s(&d(vec![1, 1])));
+	assert_eq!(S::p(&d(vec![2])).to_basis(Basis::Monomial), S::m(&d(vec![2])));
+	// s_{21} s_1 = s_{31} + s_{22} + s_{211}
+	assert_eq!(S::s(&d(vec![2, 1])) * S::s(&d(vec![1])), S::s(&d(vec![3, 1])) + S::s(&d(vec![2, 2])) + S::s(&d(vec![2, 1, 1])));
+	assert_eq!((S::e(&d(vec![1])) * S::h(&d(vec![1]))).basis(), Basis::Elementary);
+	// s_2 = (p_{11} + p_2) / 2 is not integral in the power sums
+	assert!(std::panic::catch_unwind(|| S::s(&d(vec![2])).to_basis(Basis::PowerSum)).is_err());
+	assert_eq!(T::s(&d(vec![2])).to_basis(Basis::PowerSum), T::from_terms(Basis::PowerSum, [(d(vec![1, 1]), QQ::from_pair(1, 2)), (d(vec![2]), QQ::from_pair(1, 2))]));
+
+	let bases = [Basis::Monomial, Basis::Elementary, Basis::Homogeneous, Basis::PowerSum, Basis::Schur];
+	let all : Vec<Diagram> = Diagram::partitions(4).collect();
+	for (i, l) in all.iter().enumerate() {
+		for basis in bases {
+			let f = T::from_terms(basis, [(l.clone(), QQ::from_pair(3, 1)), (all[(i + 1) % all.len()].clone(), QQ::from_pair(-1, 2)), (d(vec![2, 1]), QQ::from_pair(1, 1))]);
+			for other in bases {
+				let g = f.to_basis(other);
+				assert_eq!(g.basis(), other);
+				assert_eq!(g.to_basis(basis).terms, f.terms);
+			}
+			assert_eq!(f.omega().omega(), f);
+		}
+		// ω(h_λ) = e_λ, and ω(s_λ) = s_λ'
+		assert_eq!(S::h(l).to_basis(Basis::Schur).omega(), S::e(l));
+		assert_eq!(T::p(l).to_basis(Basis::Monomial).omega().to_basis(Basis::PowerSum).terms, T::p(l).omega().terms);
+		for m in &all {
+			// the dual bases, and the orthogonal power sums
+			assert_eq!(S::h(l).hall(&S::m(m)), if l == m { ZZ::from(1) } else { ZZ::from(0) });
+			assert_eq!(S::s(l).hall(&S::s(m).to_basis(Basis::Elementary)), if l == m { ZZ::from(1) } else { ZZ::from(0) });
+			assert_eq!(S::p(l).hall(&S::p(m)), if l == m { ZZ::from(z(&parts(l))) } else { ZZ::from(0) });
+		}
+	}
+	// h_2 h_1 h_1 = h_{211} by the product of the complete homogeneous functions
+	assert_eq!(S::s(&d(vec![2])) * S::h(&d(vec![1, 1])), S::h(&d(vec![2, 1, 1])));
+	assert_eq!(T::p(&d(vec![2])) * T::p(&d(vec![1])), T::p(&d(vec![2, 1])));
+	assert_eq!((S::s(&d(vec![1])) * S::s(&d(vec![1]))).basis(), Basis::Schur);
+	assert_eq!((S::p(&d(vec![1])) * S::p(&d(vec![1]))).basis(), Basis::PowerSum);
+
+	// over ZZ, the power sums mixed with a basis whose terms are not integral in them
+	let (p2, s2, s11) = (S::p(&d(vec![2])), S::s(&d(vec![2])), S::s(&d(vec![1, 1])));
+	assert!(p2 != s2);
+	assert_eq!(p2, s2.clone() - s11.clone());
+	assert_eq!(s2.clone() - s11.clone(), p2);
+	// p_2 + s_2 = 2 s_2 - s_{11}, which is `(p_{11} + 3 p_2) / 2`
+	let sum = p2.clone() + s2.clone();
+	assert_eq!(sum.basis(), Basis::Schur);
+	assert_eq!(sum, S::from_terms(Basis::Schur, [(d(vec![2]), ZZ::from(2)), (d(vec![1, 1]), ZZ::from(-1))]));
+	assert_eq!(p2.clone() - s2.clone(), -s11.clone());
+	assert_eq!((p2.clone() + p2.clone()).basis(), Basis::PowerSum);
+	// p_2 s_2 = s_4 + s_{22} - s_{211}, by adding the dominoes
+	let product = p2.clone() * s2.clone();
+	assert_eq!(product.basis(), Basis::Schur);
+	assert_eq!(product, (s2.clone() - s11.clone()) * s2.clone());
+	assert_eq!(product, S::from_terms(Basis::Schur, [(d(vec![4]), ZZ::from(1)), (d(vec![2, 2]), ZZ::from(1)), (d(vec![2, 1, 1]), ZZ::from(-1))]));
+	assert_eq!(s2.clone() * p2.clone(), product);
+
+	let mut vt = VarTable::new();
+	for name in ["x", "y", "z"] {
+		vt.push(name.into());
+	}
+	let vars : Vec<Var> = (0..3).map(|i| Var::from_index(i, &vt)).collect();
+	for shape in [vec![2, 1], vec![3, 1], vec![2, 2, 1], vec![1, 1, 1, 1]] {
+		assert_eq!(S::s(&d(shape.clone())).to_basis(Basis::Elementary).expand(&vars), d(shape).schur_polynomial(&vars));
+	}
+	let (x, y, z) = (MultiPolynomial::<ZZ>::from_var(vars[0]), MultiPolynomial::<ZZ>::from_var(vars[1]), MultiPolynomial::<ZZ>::from_var(vars[2]));
+	assert_eq!(S::e(&d(vec![2])).expand(&vars), x.clone() * y.clone() + y.clone() * z.clone() + z.clone() * x.clone());
+	assert_eq!(S::p(&d(vec![3])).expand(&vars), x.pow(3) + y.pow(3) + z.pow(3));
+	assert_eq!(S::m(&d(vec![1, 1, 1, 1])).expand(&vars), MultiPolynomial::zero());
+}
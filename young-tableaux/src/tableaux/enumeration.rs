@@ -0,0 +1,229 @@
+//! lazy enumeration of the semistandard and standard tableaux of a given shape
+
+use super::{Diagram, SkewDiagram, Filling, Tableau, StandardTableau, SkewTableau};
+use super::tools::VecTail;
+
+/// how the entries are chosen
+#[derive(Debug, Clone)]
+enum Mode {
+	/// the entries are at most `m`, filled cell by cell in the reading order,
+	/// where `below[k]` is the number of cells below the cell `cells[k] = (row, col)`
+	Bounded { m : usize, cells : Vec<(usize, usize)>, below : Vec<usize> },
+	/// the content is fixed, the boxes are added one by one, `values[s]` at the step `s` into the row `placed[s]`,
+	/// so that the boxes with the same value go from the bottom to the top, and make a horizontal strip,
+	/// and `lengths` is the shape filled so far
+	Content { values : Vec<usize>, placed : Vec<usize>, lengths : Vec<usize> },
+}
+
+/// the rows of the semistandard fillings of a skew shape, the holes of the inner diagram left out
+#[derive(Debug, Clone)]
+struct Fillings {
+	inner : Vec<usize>,
+	outer : Vec<usize>,
+	rows : Vec<Vec<usize>>,
+	mode : Mode,
+	started : bool,
+	exhausted : bool,
+}
+impl Fillings {
+	fn bounded(inner : &Diagram, outer : &Diagram, m : usize) -> Fillings {
+		let (inner, outer) : (Vec<usize>, Vec<usize>) = (inner.iter().take(outer.abs()).cloned().collect(), outer.iter_finite().cloned().collect());
+		let mut cells = Vec::new();
+		let mut below = Vec::new();
+		for r in 0..outer.len() {
+			for c in inner[r]..outer[r] {
+				cells.push((r, c));
+				below.push((r + 1..outer.len()).take_while(|s| inner[*s] <= c && c < outer[*s]).count());
+			}
+		}
+		let rows = (0..outer.len()).map(|r| vec![0; outer[r] - inner[r]]).collect();
+		Fillings { inner, outer, rows, mode : Mode::Bounded { m, cells, below }, started : false, exhausted : false }
+	}
+
+	fn with_content(inner : &Diagram, outer : &Diagram, content : &[usize]) -> Fillings {
+		let (inner, outer) : (Vec<usize>, Vec<usize>) = (inner.iter().take(outer.abs()).cloned().collect(), outer.iter_finite().cloned().collect());
+		let boxes = outer.iter().sum::<usize>() - inner.iter().sum::<usize>();
+		let values : Vec<usize> = content.iter().enumerate().flat_map(|(i, k)| std::iter::repeat_n(i + 1, *k)).collect();
+		let exhausted = values.len() != boxes;
+		let rows = (0..outer.len()).map(|r| Vec::with_capacity(outer[r] - inner[r])).collect();
+		let mode = Mode::Content { placed : Vec::with_capacity(values.len()), values, lengths : inner.clone() };
+		Fillings { inner, outer, rows, mode, started : false, exhausted }
+	}
+
+	/// refill the cells from `k` on by their least entries, false if some cell has no entry left
+	fn fill_bounded(&mut self, from : usize) -> bool {
+		let Mode::Bounded { m, cells, below } = &self.mode else { unreachable!() };
+		for k in from..cells.len() {
+			let (r, c) = cells[k];
+			let left = if c > self.inner[r] { self.rows[r][c - self.inner[r] - 1] } else { 1 };
+			let above = if r > 0 && c >= self.inner[r - 1] { self.rows[r - 1][c - self.inner[r - 1]] + 1 } else { 1 };
+			let least = left.max(above);
+			if least + below[k] > *m {
+				return false;
+			}
+			self.rows[r][c - self.inner[r]] = least;
+		}
+		true
+	}
+
+	/// the next filling in the bounded mode, after the entry of the last cell which can be increased
+	fn next_bounded(&mut self) -> bool {
+		let Mode::Bounded { m, cells, below } = &self.mode else { unreachable!() };
+		// the cells after it are refilled, so that their least entries never exceed their bounds again
+		match (0..cells.len()).rev().find(|k| {
+			let (r, c) = cells[*k];
+			self.rows[r][c - self.inner[r]] + below[*k] < *m
+		}) {
+			Some(k) => {
+				let (r, c) = cells[k];
+				self.rows[r][c - self.inner[r]] += 1;
+				self.fill_bounded(k + 1)
+			}
+			None => false,
+		}
+	}
+
+	/// add the boxes from the step `s` on, where the box of the step `s` goes into a row above `from`,
+	/// backtracking when there is no room left
+	fn fill_content(&mut self, mut s : usize, mut from : usize) -> bool {
+		let Mode::Content { values, placed, lengths } = &mut self.mode else { unreachable!() };
+		loop {
+			if s == values.len() {
+				return true;
+			}
+			let mut bound = from;
+			if s > 0 && values[s] == values[s - 1] {
+				bound = bound.min(placed[s - 1] + 1);
+			}
+			let addable = (0..bound).rev().find(|r| lengths[*r] < self.outer[*r] && (*r == 0 || lengths[r - 1] > lengths[*r]));
+			match addable {
+				Some(r) => {
+					lengths[r] += 1;
+					self.rows[r].push(values[s]);
+					placed.push(r);
+					s += 1;
+					from = self.outer.len();
+				}
+				None => {
+					if s == 0 {
+						return false;
+					}
+					s -= 1;
+					let r = placed.pop().unwrap();
+					lengths[r] -= 1;
+					self.rows[r].pop();
+					from = r;
+				}
+			}
+		}
+	}
+}
+impl Iterator for Fillings {
+	type Item = Vec<Vec<usize>>;
+	fn next(&mut self) -> Option<Vec<Vec<usize>>> {
+		if self.exhausted {
+			return None;
+		}
+		let found = match (&self.mode, self.started) {
+			(Mode::Bounded { .. }, false) => self.fill_bounded(0),
+			(Mode::Bounded { .. }, true) => self.next_bounded(),
+			(Mode::Content { .. }, false) => self.fill_content(0, self.outer.len()),
+			(Mode::Content { values, .. }, true) => {
+				let s = values.len();
+				if s == 0 {
+					false
+				} else {
+					let Mode::Content { placed, lengths, .. } = &mut self.mode else { unreachable!() };
+					let r = placed.pop().unwrap();
+					lengths[r] -= 1;
+					self.rows[r].pop();
+					self.fill_content(s - 1, r)
+				}
+			}
+		};
+		self.started = true;
+		if found {
+			Some(self.rows.clone())
+		} else {
+			self.exhausted = true;
+			None
+		}
+	}
+}
+
+impl Tableau {
+	/// the semistandard tableaux of shape `shape` with entries in `1..=m`, lazily
+	pub fn semistandard(shape : &Diagram, m : usize) -> impl Iterator<Item = Tableau> {
+		Fillings::bounded(&Diagram::new(), shape, m).map(|rows| Tableau::new_unchecked(Filling::new_unchecked(VecTail::from(rows, Vec::new()))))
+	}
+
+	/// the semistandard tableaux of shape `shape` with `content[i]` entries equal to `i + 1`, lazily
+	pub fn with_content(shape : &Diagram, content : &[usize]) -> impl Iterator<Item = Tableau> {
+		Fillings::with_content(&Diagram::new(), shape, content).map(|rows| Tableau::new_unchecked(Filling::new_unchecked(VecTail::from(rows, Vec::new()))))
+	}
+}
+impl StandardTableau {
+	/// the standard tableaux of shape `shape`, lazily
+	pub fn of_shape(shape : &Diagram) -> impl Iterator<Item = StandardTableau> {
+		Tableau::with_content(shape, &vec![1; shape.n()]).map(StandardTableau::new_unchecked)
+	}
+}
+impl SkewTableau {
+	/// the semistandard skew tableaux of shape `shape` with entries in `1..=m`, lazily
+	pub fn semistandard(shape : &SkewDiagram, m : usize) -> impl Iterator<Item = SkewTableau> {
+		let inner = shape.inner.clone();
+		Fillings::bounded(&shape.inner, &shape.outer, m).map(move |rows| SkewTableau::with_holes(&inner, rows))
+	}
+
+	/// the semistandard skew tableaux of shape `shape` with `content[i]` entries equal to `i + 1`, lazily
+	pub fn with_content(shape : &SkewDiagram, content : &[usize]) -> impl Iterator<Item = SkewTableau> {
+		let inner = shape.inner.clone();
+		Fillings::with_content(&shape.inner, &shape.outer, content).map(move |rows| SkewTableau::with_holes(&inner, rows))
+	}
+
+	fn with_holes(inner : &Diagram, rows : Vec<Vec<usize>>) -> SkewTableau {
+		SkewTableau::new_unchecked(VecTail::from(rows.into_iter().zip(inner.iter()).map(|(row, holes)| {
+			std::iter::repeat_n(None, *holes).chain(row.into_iter().map(Some)).collect()
+		}).collect(), Vec::new()))
+	}
+}
+#[test] fn enumeration() {
+	use general::MathClass;
+
+	let shape = Diagram::from(vec![2, 1]);
+	let all : Vec<Tableau> = Tableau::semistandard(&shape, 3).collect();
+	// the dimension of the adjoint representation of SL_3
+	assert_eq!(all.len(), 8);
+	assert!(all.iter().all(|t| t.check().is_ok() && t.shape() == shape && t.greatest() <= 3));
+	assert_eq!(all[0], Tableau::from(vec![vec![1, 1], vec![2]]));
+	assert_eq!(Tableau::semistandard(&Diagram::from(vec![1, 1, 1]), 2).count(), 0);
+	assert_eq!(Tableau::semistandard(&Diagram::new(), 2).count(), 1);
+	// the hook-content formula, 7 8 9 6 7 5 / 5 3 3
+	assert_eq!(Tableau::semistandard(&Diagram::from(vec![3, 2, 1]), 7).count(), 2352);
+
+	// the Kostka numbers K_{(3, 2), μ}
+	let shape = Diagram::from(vec![3, 2]);
+	for (content, kostka) in [(vec![3, 2], 1), (vec![2, 2, 1], 2), (vec![1, 1, 1, 1, 1], 5), (vec![2, 3], 1), (vec![4, 1], 0), (vec![1, 2, 1, 1], 3)] {
+		let tableaux : Vec<Tableau> = Tableau::with_content(&shape, &content).collect();
+		assert_eq!(tableaux.len(), kostka);
+		assert!(tableaux.iter().all(|t| t.check().is_ok() && t.content_1() == content));
+	}
+	let sum : usize = (0..=5).flat_map(|a| (0..=5 - a).map(move |b| vec![a, b, 5 - a - b]))
+		.map(|content| Tableau::with_content(&shape, &content).count()).sum();
+	assert_eq!(sum, Tableau::semistandard(&shape, 3).count());
+
+	let standard : Vec<StandardTableau> = StandardTableau::of_shape(&Diagram::from(vec![3, 2])).collect();
+	assert_eq!(standard.len(), 5);
+	assert!(standard.iter().all(|t| t.check().is_ok()));
+	assert_eq!(StandardTableau::of_shape(&Diagram::from(vec![4, 3, 2, 1])).count(), 768);
+
+	let skew = SkewDiagram::from(Diagram::from(vec![2, 1]), Diagram::from(vec![3, 2, 1]));
+	let all : Vec<SkewTableau> = SkewTableau::semistandard(&skew, 2).collect();
+	// three separate boxes
+	assert_eq!(all.len(), 8);
+	assert!(all.iter().all(|t| t.check().is_ok() && t.shape() == skew));
+	assert_eq!(all[0], SkewTableau::from(vec![vec![None, None, Some(1)], vec![None, Some(1)], vec![Some(1)]]));
+	let skew = SkewDiagram::from(Diagram::from(vec![1]), Diagram::from(vec![2, 2]));
+	assert_eq!(SkewTableau::with_content(&skew, &[1, 1, 1]).count(), 2);
+	assert_eq!(SkewTableau::semistandard(&skew, 2).count(), 2);
+}
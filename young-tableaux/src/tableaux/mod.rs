@@ -2,22 +2,40 @@ use std::fmt;
 use std::ops::{Mul, Deref, DerefMut};
 
 use super::{MathClass};
+use general::poset::Poset;
+use general::algebra::Ring;
+use general::algebra::num::{ZZ, QQ};
+
+use crate::error::{TableauError, ShapeError, OrderViolation, ContainmentError, Direction};
 
 mod tools;
+mod enumeration;
 use tools::VecTail;
 use tools::order::{is_strictly_increasing, is_weakly_increasing, replace_greatest_predecessor, replace_least_successor};
 
-#[derive(PartialEq, PartialOrd, Eq, Debug, Clone)]
-pub struct Diagram(VecTail<usize>);
-impl MathClass for Diagram {
-	fn check(&self) -> Result<(), String> {
-		if let Err(s) = self.0.is_weakly_decreasing() {
-			Err(format!("young diagram {}", s))
-		} else {
-			Ok(())
-		}
+/// rows should be weakly increasing, where the holes at the start of rows are skipped
+fn check_rows<T : PartialOrd>(rows : &VecTail<Vec<T>>, is_hole : impl Fn(&T) -> bool) -> Result<(), OrderViolation> {
+	for (row, entries) in rows.iter_finite().enumerate() {
+		let start = entries.iter().take_while(|e| is_hole(e)).count();
+		is_weakly_increasing(&entries[start..].iter().collect())
+			.map_err(|col| OrderViolation { row, col : start + col, direction : Direction::Row })?;
 	}
+	Ok(())
 }
+/// cols should be strictly increasing, where the holes at the start of cols are skipped
+fn check_cols<T : PartialOrd + Copy>(rows : &VecTail<Vec<T>>, is_hole : impl Fn(&T) -> bool) -> Result<(), OrderViolation> {
+	for (col, entries) in rows.pre_transpose().iter_finite().enumerate() {
+		let start = entries.iter().take_while(|e| is_hole(e)).count();
+		is_strictly_increasing(&entries[start..].iter().collect())
+			.map_err(|row| OrderViolation { row : start + row, col, direction : Direction::Column })?;
+	}
+	Ok(())
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Debug, Clone, MathClass)]
+#[invariant(Diagram::check_shape)]
+#[math_class(error = "TableauError", new = "from_parts", try_new = "try_from_parts")]
+pub struct Diagram(VecTail<usize>);
 impl Deref for Diagram {
 	type Target = VecTail<usize>;
 	fn deref(&self) -> &Self::Target {
@@ -39,11 +57,15 @@ impl Diagram {
 	}
 	
 	pub fn from(v : Vec<usize>) -> Diagram {
-		let diag = Diagram(VecTail::from(v, 0usize));
-		if let Err(s) = diag.check() {
-			panic!("Diagram: {}", s);
-		}
-		diag
+		Diagram::from_parts(VecTail::from(v, 0usize))
+	}
+
+	pub fn try_from(v : Vec<usize>) -> Result<Diagram, TableauError> {
+		Diagram::try_from_parts(VecTail::from(v, 0usize))
+	}
+
+	fn check_shape(&self) -> Result<(), ShapeError> {
+		self.0.is_weakly_decreasing().map_err(|row| ShapeError { row, length : self.0[row], above : self.0[row - 1] })
 	}
 
 	/// n(6, 4, 4, 2) = 6 + 4 + 4 + 2 = 16
@@ -66,7 +88,6 @@ impl Diagram {
 			let depth = self.0.iter_finite().take_while(|e| {**e > i}).count();
 			list[i] = depth;
 		}
-		println!("{:?}", list);
 		Diagram(list)
 	}
 
@@ -92,6 +113,28 @@ impl Diagram {
 		self.rows_of_corners().into_iter().map(|x| {x + 1}).collect()
 	}
 }
+#[test] fn invariants() {
+	assert_eq!(Diagram::try_from(vec![3,1,2]), Err(TableauError::Shape(ShapeError { row : 2, length : 2, above : 1 })));
+	assert_eq!(Diagram::try_from(vec![3,2,1]), Ok(Diagram::from(vec![3,2,1])));
+	assert_eq!(
+		Tableau::try_from(vec![vec![1,2,2], vec![2,2]]),
+		Err(TableauError::Order(OrderViolation { row : 1, col : 1, direction : Direction::Column }))
+	);
+	assert_eq!(
+		SkewTableau::try_from(vec![vec![None, Some(3), Some(2)]]),
+		Err(TableauError::Order(OrderViolation { row : 0, col : 2, direction : Direction::Row }))
+	);
+	assert_eq!(
+		SkewDiagram::try_from(Diagram::from(vec![2,2]), Diagram::from(vec![3,1])),
+		Err(TableauError::Containment(ContainmentError { row : 1, inner : 2, outer : 1 }))
+	);
+	assert!(std::panic::catch_unwind(|| {
+		Tableau::from_parts(Filling::from(vec![vec![1,2], vec![1]]))
+	}).is_err());
+	if !cfg!(debug_assertions) {
+		Diagram::new_unchecked(VecTail::from(vec![1,2], 0));
+	}
+}
 #[test] fn conjugate() {
 	let diagram = Diagram::from(vec![6,6,3,2,1]);
 	assert_eq!(diagram.conjugate(), Diagram::from(vec![5,4,3,2, 2,2]));
@@ -101,6 +144,171 @@ impl Diagram {
 	assert_eq!(diagram.rows_of_corners(), vec![0,2,3]);
 }
 
+/// a diagram with at most `n` rows is a dominant weight of `GL_n`,
+/// and of `SL_n`, the root system `A_{n-1}`, after forgetting the columns of length `n`
+impl Diagram {
+	/// the dominant weight `Σ (λ_i - λ_{i+1}) ω_i` of `A_{n-1}`, in the fundamental weights
+	pub fn to_weight(&self, n : usize) -> Vec<i64> {
+		if n == 0 {
+			panic!("Diagram: there is no A_{{-1}}, the rank n should be positive")
+		}
+		if self.abs() > n {
+			panic!("Diagram: {} rows are too many for a weight of A_{}", self.abs(), n - 1)
+		}
+		(0..n - 1).map(|i| (self.0[i] - self.0[i + 1]) as i64).collect()
+	}
+
+	/// the diagram without columns of length `n` of a dominant weight of `A_{n-1}`
+	pub fn from_weight(weight : &[i64]) -> Diagram {
+		if weight.iter().any(|x| *x < 0) {
+			panic!("Diagram: the weight {:?} is not dominant", weight)
+		}
+		let mut rows : Vec<usize> = weight.iter().rev().scan(0, |acc, x| {
+			*acc += *x as usize;
+			Some(*acc)
+		}).collect();
+		rows.reverse();
+		Diagram::from(rows)
+	}
+}
+#[test] fn weight() {
+	use general::root_system::{RootSystem, CartanType};
+	let diagram = Diagram::from(vec![4, 2, 2]);
+	assert_eq!(diagram.to_weight(4), vec![2, 0, 2]);
+	assert_eq!(Diagram::from_weight(&[2, 0, 2]), diagram);
+	// columns of full length are the determinant, which is trivial on SL_3
+	assert_eq!(Diagram::from_weight(&Diagram::from(vec![3, 2, 1]).to_weight(3)), Diagram::from(vec![2, 1]));
+	// the adjoint representation of SL_3, and the symmetric cube
+	let a2 = RootSystem::new(CartanType::A(2));
+	assert_eq!(*a2.weyl_dimension(&Diagram::from(vec![2, 1]).to_weight(3)), 8);
+	assert_eq!(*a2.weyl_dimension(&Diagram::from(vec![3]).to_weight(3)), 10);
+	assert_eq!(Diagram::new().to_weight(1), Vec::<i64>::new());
+	for diagram in [Diagram::new(), Diagram::from(vec![1])] {
+		let message = std::panic::catch_unwind(|| diagram.to_weight(0)).unwrap_err();
+		assert_eq!(message.downcast_ref::<&str>().copied(), Some("Diagram: there is no A_{-1}, the rank n should be positive"));
+	}
+}
+
+/// the cells are `(row, col)`, counted from 0
+impl Diagram {
+	/// the cells row by row, from left to right
+	pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+		self.0.iter_finite().enumerate().flat_map(|(row, len)| (0..*len).map(move |col| (row, col)))
+	}
+
+	fn assert_cell(&self, row : usize, col : usize) {
+		if col >= self.0[row] {
+			panic!("Diagram: ({}, {}) is not a cell", row, col)
+		}
+	}
+
+	/// the number of cells on the right of `(row, col)`
+	pub fn arm(&self, row : usize, col : usize) -> usize {
+		self.assert_cell(row, col);
+		self.0[row] - col - 1
+	}
+
+	/// the number of cells below `(row, col)`
+	pub fn leg(&self, row : usize, col : usize) -> usize {
+		self.assert_cell(row, col);
+		self.0.iter_finite().skip(row + 1).take_while(|len| **len > col).count()
+	}
+
+	/// the cell itself, its arm and its leg
+	pub fn hook(&self, row : usize, col : usize) -> usize {
+		self.arm(row, col) + self.leg(row, col) + 1
+	}
+
+	/// the diagonal of `(row, col)`, `col - row`
+	pub fn content(&self, row : usize, col : usize) -> i64 {
+		self.assert_cell(row, col);
+		col as i64 - row as i64
+	}
+
+	/// `f^λ = n! / Π h(c)`, by the hook-length formula
+	pub fn count_standard_tableaux(&self) -> ZZ {
+		// pair the factors of `n!` with the hooks, so that the partial products stay small
+		let f = self.cells().enumerate().fold(QQ::one(), |acc, (k, (row, col))| {
+			acc * QQ::from_pair(k as i128 + 1, self.hook(row, col) as i128)
+		});
+		ZZ::from(f.numerator())
+	}
+
+	/// the number of semistandard tableaux with entries in `1..=m`, `Π (m + c(c)) / h(c)` by the hook-content formula,
+	/// which is the dimension of the representation of `GL_m` of highest weight `λ`
+	pub fn count_semistandard_tableaux(&self, m : usize) -> ZZ {
+		let count = self.cells().fold(QQ::one(), |acc, (row, col)| {
+			acc * QQ::from_pair(m as i128 + self.content(row, col) as i128, self.hook(row, col) as i128)
+		});
+		ZZ::from(count.numerator())
+	}
+}
+#[test] fn hooks() {
+	use general::root_system::{RootSystem, CartanType};
+	let diagram = Diagram::from(vec![4, 3, 1]);
+	assert_eq!(diagram.cells().count(), 8);
+	assert_eq!((diagram.arm(0, 1), diagram.leg(0, 1), diagram.hook(0, 1)), (2, 1, 4));
+	assert_eq!(diagram.hook(0, 0), 6);
+	assert_eq!(diagram.content(2, 0), -2);
+	assert!(std::panic::catch_unwind(|| Diagram::from(vec![2]).hook(0, 2)).is_err());
+	// the staircase (5, 4, 3, 2, 1)
+	assert_eq!(Diagram::from(vec![5, 4, 3, 2, 1]).count_standard_tableaux(), ZZ::from(292864));
+	assert_eq!(Diagram::from(vec![10, 10, 10]).count_standard_tableaux(), ZZ::from(7646001090));
+	// 60! alone overflows `i128`
+	assert_eq!(Diagram::from(vec![20, 20, 20]).count_standard_tableaux(), ZZ::from(119115896614816702500900));
+	assert_eq!(Diagram::new().count_standard_tableaux(), ZZ::from(1));
+	assert_eq!(Diagram::from(vec![3]).count_semistandard_tableaux(0), ZZ::from(0));
+
+	for shape in [vec![3, 2], vec![2, 2, 1], vec![4, 1, 1], vec![3, 3, 1, 1]] {
+		let diagram = Diagram::from(shape);
+		assert_eq!(*diagram.count_standard_tableaux(), StandardTableau::of_shape(&diagram).count() as i128);
+		for m in 1..=4 {
+			assert_eq!(*diagram.count_semistandard_tableaux(m), Tableau::semistandard(&diagram, m).count() as i128);
+		}
+		let m = 5;
+		let gl = RootSystem::new(CartanType::A(m - 1));
+		assert_eq!(diagram.count_semistandard_tableaux(m), gl.weyl_dimension(&diagram.to_weight(m)));
+	}
+}
+
+impl Diagram {
+	/// the interval `[self, upper]` of Young's lattice, the diagrams between them by containment
+	pub fn interval(&self, upper : &Diagram) -> Poset<Diagram> {
+		let mut diagrams = Vec::new();
+		if self <= upper {
+			let mut rows = Vec::new();
+			self.fill_rows(upper, &mut rows, &mut diagrams);
+		}
+		Poset::from_partial_ord(diagrams)
+	}
+	/// choose the rows one by one, each between the row of `self` and the rows of `upper` and above
+	fn fill_rows(&self, upper : &Diagram, rows : &mut Vec<usize>, diagrams : &mut Vec<Diagram>) {
+		let i = rows.len();
+		if i == upper.abs() {
+			diagrams.push(Diagram::from(rows.clone()));
+			return;
+		}
+		let most = rows.last().map_or(upper.0[i], |r| upper.0[i].min(*r));
+		for r in self.0[i]..=most {
+			rows.push(r);
+			self.fill_rows(upper, rows, diagrams);
+			rows.pop();
+		}
+	}
+}
+#[test] fn young_lattice() {
+	let interval = Diagram::from(vec![1]).interval(&Diagram::from(vec![2, 2]));
+	// (1), (2), (1, 1), (2, 1), (2, 2)
+	assert_eq!(interval.len(), 5);
+	assert_eq!(interval.covers().len(), 5);
+	// Young's lattice is distributive, so the Möbius function of [(1), (2, 2)] vanishes
+	let (bottom, top) = (interval.index_of(&Diagram::from(vec![1])).unwrap(), interval.index_of(&Diagram::from(vec![2, 2])).unwrap());
+	assert_eq!(*interval.moebius(bottom, top), 0);
+	// the maximal chains are the standard tableaux of the skew shape (2, 2) / (1)
+	assert_eq!(interval.maximal_chains().len(), 2);
+	assert_eq!(Diagram::from(vec![2]).interval(&Diagram::from(vec![1, 1])).len(), 0);
+}
+
 impl fmt::Display for Diagram {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		for &len in self.0.iter_finite() {
@@ -123,34 +331,24 @@ impl fmt::Display for Diagram {
 	// println!("{}", diagram_l);
 	assert!(diagram_e == diagram);
 	assert!(diagram_l <= diagram);
+	// the rows past the shorter diagram are compared as well
+	assert!(Diagram::from(vec![1]) < Diagram::from(vec![1,1]));
+	assert_eq!(Diagram::from(vec![2]).partial_cmp(&Diagram::from(vec![1,1])), None);
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, MathClass)]
+#[invariant(SkewDiagram::check_containment)]
+#[math_class(error = "TableauError", vis = "pub", new = "from", try_new = "try_from")]
 pub struct SkewDiagram {
 	inner : Diagram,
 	outer : Diagram,
 }
-impl MathClass for SkewDiagram {
-	fn check(&self) -> Result<(), String> {
-		for (&i, &o) in self.inner.0.iter().take_while(|i| {**i > 0}).zip(self.outer.0.iter()) {
-			if i > o {
-				return Err("the inner Diagram should be \"inside\" of outer Diagram".into())
-			}
-		}
-		Ok(())
-	}
-}
 impl SkewDiagram {
-	pub fn from(inner : Diagram, outer : Diagram) -> SkewDiagram {
-		let skew_diagram = SkewDiagram {
-			inner,
-			outer,
-		};
-		
-		if let Err(s) = skew_diagram.check(){
-			panic!("SkewDiagram: {}", s);
+	fn check_containment(&self) -> Result<(), ContainmentError> {
+		match self.inner.0.iter_finite().zip(self.outer.0.iter()).position(|(i, o)| i > o) {
+			Some(row) => Err(ContainmentError { row, inner : self.inner.0[row], outer : self.outer.0[row] }),
+			None => Ok(()),
 		}
-		skew_diagram
 	}
 
 	/// the common corners of inner and outer
@@ -245,14 +443,10 @@ impl Mul for Diagram {
 }
 
 // -------------------------------------------------------------
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, MathClass)]
+#[invariant(Filling::check_shape)]
+#[math_class(error = "TableauError", new = "from_parts", try_new = "try_from_parts")]
 pub struct Filling(VecTail<Vec<usize>>);
-impl MathClass for Filling {
-	fn check(&self) -> Result<(), String> {
-		self.shape().check()?;
-		Ok(())
-	}
-}
 impl Deref for Filling {
 	type Target = VecTail<Vec<usize>>;
 	fn deref(&self) -> &Self::Target {
@@ -270,12 +464,17 @@ impl Filling {
 	}
 
 	pub fn from(v : Vec<Vec<usize>>) -> Filling {
-		let filling = Filling(VecTail::from(v, Vec::new()));
+		Filling::from_parts(VecTail::from(v, Vec::new()))
+	}
 
-		if let Err(s) = filling.check() {
-			panic!("Filling: {}", s);
-		}
-		filling
+	pub fn try_from(v : Vec<Vec<usize>>) -> Result<Filling, TableauError> {
+		Filling::try_from_parts(VecTail::from(v, Vec::new()))
+	}
+
+	/// the lengths of rows make up a diagram
+	fn check_shape(&self) -> Result<(), TableauError> {
+		Diagram::try_from(self.0.iter_finite().map(Vec::len).collect())?;
+		Ok(())
 	}
 
 	pub fn is_empty(&self) -> bool {
@@ -308,22 +507,14 @@ impl Filling { // convert -- similiarly
 	}
 }
 
-#[derive(PartialEq, Eq, Debug, Clone)]
+use std::collections::HashSet;
+#[derive(PartialEq, Eq, Debug, Clone, MathClass)]
+#[invariant(self.refer.check())]
+#[invariant(self.0.iter_finite().flatten().collect::<HashSet<_>>().len() == self.shape().n(), "Numbering should have distinct entries")]
+#[math_class(error = "TableauError", new = "from_parts", try_new = "try_from_parts")]
 pub struct Numbering {
 	refer : Filling,
 }
-use std::collections::HashSet;
-impl MathClass for Numbering {
-	fn check(&self) -> Result<(), String> {
-		self.refer.check()?;
-
-		if self.0.iter_finite().flatten().collect::<HashSet<_>>().len() != self.shape().n() {
-			Err("Numbering should have distinct entries".into())
-		} else {
-			Ok(())
-		}
-	}
-}
 #[test] fn numbering() {
 	let numbering = Numbering {
 		refer : Filling::from(vec![vec![1,2,3,4], vec![2,3,4]])
@@ -353,14 +544,11 @@ impl Numbering {
 	}
 
 	pub fn from(v : Vec<Vec<usize>>) -> Numbering {
-		let numbering = Numbering {
-			refer : Filling::from(v)
-		};
+		Numbering::from_parts(Filling::from(v))
+	}
 
-		if let Err(s) = numbering.check() {
-			panic!("Numbering: {}", s);
-		}
-		numbering
+	pub fn try_from(v : Vec<Vec<usize>>) -> Result<Numbering, TableauError> {
+		Numbering::try_from_parts(Filling::try_from(v)?)
 	}
 
 	// ? to complicated 
@@ -385,7 +573,11 @@ impl Numbering { // convert -- similiarly
 	}
 }
 
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, MathClass)]
+#[invariant(self.refer.check())]
+#[invariant(check_rows(&self.0, |_| false))]
+#[invariant(check_cols(&self.0, |_| false))]
+#[math_class(error = "TableauError", new = "from_parts", try_new = "try_from_parts")]
 pub struct Tableau {
 	refer : Filling,
 }
@@ -400,25 +592,6 @@ impl DerefMut for Tableau {
 		&mut self.refer
     }
 }
-impl MathClass for Tableau {
-	// ? too complicated
-	// ! direct copied from below
-	fn check(&self) -> Result<(), String> {
-		self.refer.check()?;
-
-		for row in self.0.iter_finite() {
-			if let Err(s) = is_weakly_increasing(row) {
-				return Err(format!("rows in tableau {}", s));
-			}
-		}
-		for col in self.pre_transpose().iter_finite() {
-			if let Err(s) = is_strictly_increasing(col) {
-				return Err(format!("cols in tableau {}", s))
-			}
-		}
-		Ok(())
-	}
-}
 impl Tableau {
 	pub fn new() -> Tableau {
 		Tableau {
@@ -427,14 +600,11 @@ impl Tableau {
 	}
 
 	pub fn from(v : Vec<Vec<usize>>) -> Tableau {
-		let tableau = Tableau{
-			refer : Filling::from(v)
-		};
+		Tableau::from_parts(Filling::from(v))
+	}
 
-		if let Err(s) = tableau.check() {
-			panic!("Tableau: {}", s);
-		}
-		tableau
+	pub fn try_from(v : Vec<Vec<usize>>) -> Result<Tableau, TableauError> {
+		Tableau::try_from_parts(Filling::try_from(v)?)
 	}
 
 	/// return the row_index of the greatest element
@@ -570,19 +740,29 @@ impl Tableau {
 	/// ? to complicated
 	/// return the value inserted 
 	pub fn reverse_bumping(&mut self, row_index : usize) -> usize {
-		if let Some(mut bumped) = self.0[row_index].pop() {
-			for row in self.0.iter_finite_mut().take(row_index).rev() { // ?
-				if let Some(tmp) = replace_greatest_predecessor(bumped, row) {
-					bumped = tmp;
-				} else {
-					panic!("it is not a well defined tableau")
-				}
-			}
-			bumped
-		} else {
-			panic!("no possible \"new block\" in this row")
+		match self.try_reverse_bumping(row_index) {
+			Ok(value) => value,
+			Err(e) => panic!("Tableau: {}", e),
 		}
 	}
+
+	/// return the value inserted, or why there is no "new block" in this row
+	pub fn try_reverse_bumping(&mut self, row_index : usize) -> Result<usize, TableauError> {
+		if self[row_index].is_empty() {
+			return Err(TableauError::EmptyRow(row_index));
+		} else if self[row_index + 1].len() == self[row_index].len() {
+			return Err(TableauError::NoCorner(row_index));
+		}
+		// the bumping is done on a copy of the rows, so that `self` is left as it is on an error
+		let mut rows = self.0.clone();
+		let mut bumped = rows[row_index].pop().unwrap();
+		for (row, entries) in rows.iter_finite_mut().take(row_index).enumerate().rev() {
+			bumped = replace_greatest_predecessor(bumped, entries)
+				.ok_or(OrderViolation { row : row + 1, col : 0, direction : Direction::Column })?;
+		}
+		self.0 = rows;
+		Ok(bumped)
+	}
 }
 #[test] fn bumping() {
 	let mut tableau = Tableau::from(vec![
@@ -635,13 +815,23 @@ impl Tableau {
 	}
 	
 	pub fn pop_at_row(&mut self, row_index : usize) -> usize {
-		if let Some(value) = self[row_index].pop() {
-			self.strip(); // ?
-			value
-		} else {
-			panic!("this row is empty, you cannot pop at here");
+		match self.try_pop_at_row(row_index) {
+			Ok(value) => value,
+			Err(e) => panic!("Tableau: {}", e),
 		}
 	}
+
+	/// remove the last box of this row, which should be a corner
+	pub fn try_pop_at_row(&mut self, row_index : usize) -> Result<usize, TableauError> {
+		if self[row_index].is_empty() {
+			return Err(TableauError::EmptyRow(row_index));
+		} else if self[row_index + 1].len() == self[row_index].len() {
+			return Err(TableauError::NoCorner(row_index));
+		}
+		let value = self[row_index].pop().unwrap();
+		self.strip(); // ?
+		Ok(value)
+	}
 }
 #[test] fn place_and_pop() {
 	let mut tableau = Tableau::from(vec![
@@ -653,6 +843,21 @@ impl Tableau {
 	// println!("{}", tableau);
 	tableau.pop_at_row(1);
 	// println!("{}", tableau);
+	assert_eq!(tableau.try_pop_at_row(2), Err(TableauError::EmptyRow(2)));
+	assert_eq!(tableau.try_reverse_bumping(2), Err(TableauError::EmptyRow(2)));
+	assert_eq!(Tableau::from(vec![vec![1, 2], vec![3, 4]]).try_pop_at_row(0), Err(TableauError::NoCorner(0)));
+	let mut square = Tableau::from(vec![vec![1, 2], vec![3, 4]]);
+	assert_eq!(square.try_reverse_bumping(0), Err(TableauError::NoCorner(0)));
+	assert_eq!(square, Tableau::from(vec![vec![1, 2], vec![3, 4]]));
+	// no entry of the first row is less than the `1` below it
+	let mut broken = Tableau::from(vec![vec![1, 2], vec![3]]);
+	broken[1][0] = 1;
+	let before = broken.clone();
+	assert_eq!(broken.try_reverse_bumping(1), Err(TableauError::Order(OrderViolation { row : 1, col : 0, direction : Direction::Column })));
+	assert_eq!(broken, before);
+	let mut skew_tableau = SkewTableau::from(vec![vec![None, Some(1)], vec![Some(2)]]);
+	assert_eq!(skew_tableau.try_sliding(1), Err(TableauError::NoCorner(1)));
+	assert_eq!(skew_tableau.try_reverse_sliding(3), Err(TableauError::NoCorner(3)));
 }
 
 impl fmt::Display for Tableau {
@@ -676,23 +881,13 @@ impl fmt::Display for Tableau {
 	assert_eq!(format!("{}", tableau), String::from("2 6 7 8 \n5 7 8 \n6 8 \n"));
 }
 
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, MathClass)]
+#[invariant(self.check_shape())]
+#[invariant(check_cols(&self.0, |_| false))]
+#[math_class(error = "TableauError", new = "from_parts", try_new = "try_from_parts")]
 pub struct StandardTableau {
 	refer : Tableau
 }
-impl MathClass for StandardTableau {
-	// ? too complicated
-	fn check(&self) -> Result<(), String> {
-		self.shape().check()?;
-
-		for col in self.pre_transpose().iter_finite() {
-			if let Err(s) = is_strictly_increasing(col) {
-				return Err(format!("cols in tableau {}", s))
-			}
-		}
-		Ok(())
-	}
-}
 impl Deref for StandardTableau {
 	type Target = Tableau;
 	fn deref(&self) -> &Self::Target {
@@ -718,14 +913,11 @@ impl StandardTableau {
 	}
 
 	pub fn from(v : Vec<Vec<usize>>) -> StandardTableau {
-		let tableau = StandardTableau {
-			refer : Tableau::from(v)
-		};
+		StandardTableau::from_parts(Tableau::from(v))
+	}
 
-		if let Err(s) = tableau.check() {
-			panic!("StandardTableau: {}", s);
-		}
-		tableau
+	pub fn try_from(v : Vec<Vec<usize>>) -> Result<StandardTableau, TableauError> {
+		StandardTableau::try_from_parts(Tableau::try_from(v)?)
 	}
 
 	pub fn transpose(&self) -> StandardTableau {
@@ -760,26 +952,12 @@ impl fmt::Display for StandardTableau {
 }
 
 // ------------------------------------------------------
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Eq, Debug, Clone, MathClass)]
+#[invariant(SkewTableau::check_shape)]
+#[invariant(check_rows(&self.0, Option::is_none))]
+#[invariant(check_cols(&self.0, Option::is_none))]
+#[math_class(error = "TableauError", new = "from_parts", try_new = "try_from_parts")]
 pub struct SkewTableau(VecTail<Vec<Option<usize>>>);
-impl MathClass for SkewTableau {
-	// ? too complicated
-	fn check(&self) -> Result<(), String> {
-		self.shape().check()?;
-
-		for row in self.0.iter_finite() {
-			if let Err(s) = is_weakly_increasing(&row.iter().skip_while(|e| e.is_none()).collect()) {
-				return Err(format!("rows in tableau {}", s));
-			}
-		}
-		for col in self.pre_transpose().iter_finite() {
-			if let Err(s) = is_strictly_increasing(&col.iter().skip_while(|e| e.is_none()).collect()) {
-				return Err(format!("cols in tableau {}", s))
-			}
-		}
-		Ok(())
-	}
-}
 #[test] fn check_skew_tableau() {
 	assert!(std::panic::catch_unwind(|| {
 		SkewTableau::from(vec![
@@ -819,12 +997,20 @@ impl SkewTableau {
 		SkewTableau(VecTail::new())
 	}
 	pub fn from(v : Vec<Vec<Option<usize>>>) -> SkewTableau {
-		let tableau = SkewTableau(VecTail::from(v, Vec::new()));
-		
-		if let Err(s) = tableau.check() {
-			panic!("SkewTableau: {}", s);
-		}
-		tableau
+		SkewTableau::from_parts(VecTail::from(v, Vec::new()))
+	}
+
+	pub fn try_from(v : Vec<Vec<Option<usize>>>) -> Result<SkewTableau, TableauError> {
+		SkewTableau::try_from_parts(VecTail::from(v, Vec::new()))
+	}
+
+	/// the holes at the start of rows make up the inner diagram, inside the diagram of all boxes
+	fn check_shape(&self) -> Result<(), TableauError> {
+		SkewDiagram::try_from(
+			Diagram::try_from(self.0.iter_finite().map(|v| {v.iter().take_while(|e| {e.is_none()}).count()}).collect())?,
+			Diagram::try_from(self.0.iter_finite().map(Vec::len).collect())?,
+		)?;
+		Ok(())
 	}
 
 	pub fn is_empty(&self) -> bool {
@@ -932,14 +1118,22 @@ impl SkewTableau { // convert -- similiarly
 
 impl SkewTableau {
 	/// return the index of the removed corner
-	pub fn sliding(&mut self, mut row_index : usize) -> usize {
-		println!("{}", self);
+	pub fn sliding(&mut self, row_index : usize) -> usize {
+		match self.try_sliding(row_index) {
+			Ok(row) => row,
+			Err(e) => panic!("SkewTableau: {}", e),
+		}
+	}
+
+	/// return the index of the removed corner, or `NoCorner` if there is no inner corner in this row
+	pub fn try_sliding(&mut self, mut row_index : usize) -> Result<usize, TableauError> {
+		// println!("{}", self);
 		if self.shape().inner.rows_of_corners().contains(&row_index) {
 			let mut col_index = self.shape().inner.0[row_index] - 1; // the last one
 			// * `hole` is self.0[row_index][col_index]
 			loop {
-				println!("{}", self);
-				println!("hole: ({}, {})", col_index, row_index);
+				// println!("{}", self);
+				// println!("hole: ({}, {})", col_index, row_index);
 				let south_one = {
 					let next_row = &mut self.0[row_index + 1];
 					if let Some(e) = next_row.get_mut(col_index) {
@@ -948,18 +1142,18 @@ impl SkewTableau {
 						None
 					}
 				};
-				println!("below one: {:?}", south_one);
+				// println!("below one: {:?}", south_one);
 				let east_one = match self.0[row_index].get_mut(col_index + 1) {
 					Some(e) => e.take(),
 					None => None
 				};
-				println!("right one: {:?}", east_one);
+				// println!("right one: {:?}", east_one);
 				
 				if (south_one, east_one) == (None, None) {
 					self.0[row_index].pop(); // remove the hole
-					println!("end");
-					println!("{}", self);
-					break row_index;
+					// println!("end");
+					// println!("{}", self);
+					break Ok(row_index);
 
 				} else if (east_one.is_some() && east_one < south_one) || south_one.is_none() {
 					self.0[row_index][col_index] = east_one;
@@ -967,7 +1161,7 @@ impl SkewTableau {
 						self.0[row_index + 1][col_index] = south_one;
 					}
 					col_index += 1;
-					println!("move to right");
+					// println!("move to right");
 				} else if (south_one.is_some() && east_one >= south_one) || east_one.is_none(){
 					// if they are equal, move to the below one
 					self.0[row_index][col_index] = south_one;
@@ -975,14 +1169,14 @@ impl SkewTableau {
 						self.0[row_index][col_index + 1] = east_one;
 					}
 					row_index += 1;
-					println!("move to below");
+					// println!("move to below");
 				} else {
 					panic!("Tell me, WTH is the else case?!")
 				}
 				
 			}
 		} else {
-			panic!("no inner corners in this row")
+			Err(TableauError::NoCorner(row_index))
 		}
 	}
 
@@ -1006,7 +1200,15 @@ impl SkewTableau {
 
 	/// note: `reverse` of sliding(1) may not be the reverse_sliding(1), it should be reverse_sliding(sliding(1))
 	/// return the row of new box
-	pub fn reverse_sliding(&mut self, mut row_index : usize) -> usize {
+	pub fn reverse_sliding(&mut self, row_index : usize) -> usize {
+		match self.try_reverse_sliding(row_index) {
+			Ok(row) => row,
+			Err(e) => panic!("SkewTableau: {}", e),
+		}
+	}
+
+	/// return the row of new box, or `NoCorner` if a box cannot be added at the end of this row
+	pub fn try_reverse_sliding(&mut self, mut row_index : usize) -> Result<usize, TableauError> {
 		// println!("{}", self);
 		if self.shape().outer.rows_below_corners().contains(&row_index) || row_index == 0 {
 			let mut col_index = *self.shape().outer.0.iter().nth(row_index).unwrap(); // the one right to the last one
@@ -1031,7 +1233,7 @@ impl SkewTableau {
 				
 				if (north_one, west_one) == (None, None) {
 					// println!("end");
-					break row_index;
+					break Ok(row_index);
 
 				} else if (west_one.is_some() && west_one > north_one) || north_one.is_none() {
 					self.0[row_index][col_index] = west_one;
@@ -1054,7 +1256,7 @@ impl SkewTableau {
 				
 			}
 		} else {
-			panic!("no potential common_corners in this row")
+			Err(TableauError::NoCorner(row_index))
 		}
 	}
 }
@@ -1164,17 +1366,10 @@ impl fmt::Display for SkewTableau {
 	assert_eq!(format!("{}", tableau), String::from("□ 6 7 8 \n5 7 8 \n6 8 \n"));
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, MathClass)]
+#[invariant(self.0.shape() == self.1.shape(), "these two tableau has different shape")]
+#[math_class(error = "TableauError", vis = "pub", new = "from", try_new = "try_from")]
 pub struct TableauPair(Tableau, Tableau);
-impl MathClass for TableauPair {
-	fn check(&self) -> Result<(), String> {
-		if self.0.shape() != self.1.shape() {
-			Err("these two tableau has different shape".into())
-		} else {
-			Ok(())
-		}
-	}
-}
 impl fmt::Display for TableauPair {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(f, "P:\n{}\nQ:\n{}", self.value_tableau(), self.index_tableau())
@@ -1182,15 +1377,6 @@ impl fmt::Display for TableauPair {
 }
 
 impl TableauPair {
-	#[allow(non_snake_case)]
-	pub fn from(P : Tableau, Q : Tableau) -> TableauPair {
-		let T = TableauPair(P, Q);
-		if let Err(s) = T.check() {
-			panic!("TableauPair: {}", s);
-		}
-		T
-	}
-
 	/// P in (P, Q)
 	pub fn value_tableau(&self) -> Tableau {
 		self.0.clone()
@@ -83,9 +83,9 @@ impl<T : PartialEq> VecTail<T> {
 	assert_eq!(v.length, 4);
 }
 impl<T : PartialOrd> VecTail<T> {
-	pub fn is_weakly_decreasing(&self) -> Result<(), String> {
-		order::is_weakly_decreasing(&self.iter_finite().collect())?;
-		Ok(())
+	/// the index of the first entry out of order otherwise
+	pub fn is_weakly_decreasing(&self) -> Result<(), usize> {
+		order::is_weakly_decreasing(&self.iter_finite().collect())
 	}
 }
 impl<T : PartialEq> PartialEq for VecTail<T> {
@@ -96,7 +96,9 @@ impl<T : PartialEq> PartialEq for VecTail<T> {
 impl<T : Ord> PartialOrd for VecTail<T> {
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
 		let (mut less, mut equal, mut greater) = (0, 0, 0);
-		for (s, o) in self.iter().zip(other.iter()).take(max(self.length, other.length)) {
+		// the tails are compared as well when the lengths differ
+		let length = max(self.length, other.length);
+		for (s, o) in self.iter().zip(other.iter()).take(length) {
 			match s.cmp(o) {
 				Ordering::Less => less += 1,
 				Ordering::Greater => greater += 1,
@@ -110,11 +112,11 @@ impl<T : Ord> PartialOrd for VecTail<T> {
 
 		// println!("{},{},{},{}", equal, less, greater, self.length);
 
-		if equal == self.length {
+		if equal == length {
 			Some(Ordering::Equal)
-		} else if less == self.length {
+		} else if less == length {
 			Some(Ordering::Less)
-		} else if greater == self.length {
+		} else if greater == length {
 			Some(Ordering::Greater)
 		} else {
 			None
@@ -152,6 +154,9 @@ impl<T : Ord> PartialOrd for VecTail<T> {
 	let inf_3 = VecTail::from(vec![1,2,2,4], 0);
 	assert!(inf_1 <= inf_2);
 	assert!(inf_3 < inf_2);
+	assert!(VecTail::from(vec![1], 0) < VecTail::from(vec![1,1], 0));
+	assert!(VecTail::from(vec![1,1], 0) > VecTail::from(vec![1], 0));
+	assert_eq!(VecTail::from(vec![2], 0).partial_cmp(&VecTail::from(vec![1,1], 0)), None);
 }
 impl<T : PartialEq + Copy> VecTail<Vec<T>> {
 	pub fn pre_transpose(&self) -> VecTail<Vec<T>> {
@@ -1,20 +1,22 @@
 use std::cmp::{PartialOrd};
 
-pub fn is_weakly_decreasing<T : PartialOrd>(v : &Vec<T>) -> Result<(), &'static str> {
+// the checks return the index of the first entry out of order
+
+pub fn is_weakly_decreasing<T : PartialOrd>(v : &Vec<T>) -> Result<(), usize> {
 	if !v.is_empty() {
 		for index in 0..(v.len() - 1) {
 			if v[index + 1] > v[index] {
-				return Err("should be weakly deceasing");
+				return Err(index + 1);
 			}
 		}
 	}
 	Ok(())
 }
-pub fn is_weakly_increasing<T : PartialOrd>(v : &Vec<T>) -> Result<(), &'static str> {
+pub fn is_weakly_increasing<T : PartialOrd>(v : &Vec<T>) -> Result<(), usize> {
 	if !v.is_empty() {
 		for index in 0..(v.len() - 1) {
 			if v[index + 1] < v[index] {
-				return Err("should be weakly inceasing");
+				return Err(index + 1);
 			}
 		}
 	}
@@ -22,21 +24,21 @@ pub fn is_weakly_increasing<T : PartialOrd>(v : &Vec<T>) -> Result<(), &'static
 }
 
 #[allow(dead_code)]
-pub fn is_strictly_decreasing<T : PartialOrd>(v : &Vec<T>) -> Result<(), &'static str> {
+pub fn is_strictly_decreasing<T : PartialOrd>(v : &Vec<T>) -> Result<(), usize> {
 	if !v.is_empty() {
 		for index in 0..(v.len() - 1) {
 			if v[index + 1] >= v[index] {
-				return Err("should be strictly deceasing");
+				return Err(index + 1);
 			}
 		}
 	}
 	Ok(())
 }
-pub fn is_strictly_increasing<T : PartialOrd>(v : &Vec<T>) -> Result<(), &'static str> {
+pub fn is_strictly_increasing<T : PartialOrd>(v : &Vec<T>) -> Result<(), usize> {
 	if !v.is_empty() {
 		for index in 0..(v.len() - 1) {
 			if v[index + 1] <= v[index] {
-				return Err("should be strictly inceasing");
+				return Err(index + 1);
 			}
 		}
 	}
@@ -1,6 +1,11 @@
 use std::fmt;
 use std::ops::{Mul};
 
+use general::poset::Poset;
+
+#[cfg(test)]
+use crate::Diagram;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Word(pub Vec<usize>);
 impl Word {
@@ -22,6 +27,20 @@ impl Word {
 	assert_eq!(word.L(3), 6);
 	assert_eq!(word.L(4), 6);
 }
+impl Word {
+	/// the positions, with `i <= j` if `i <= j` and `w_i <= w_j`, whose chains are the weakly increasing subsequences
+	pub fn to_poset(&self) -> Poset<usize> {
+		Poset::new((0..self.0.len()).collect(), |i, j| i <= j && self.0[*i] <= self.0[*j])
+	}
+}
+#[test] fn greene_kleitman() {
+	// Greene's theorem: the Greene–Kleitman partition of the word is the shape of its tableau
+	for word in [Word(vec![1, 4, 2, 5, 6, 3]), Word(vec![3, 1, 2, 2, 1, 3, 1]), Word(vec![5, 4, 3, 2, 1])] {
+		let lambda = word.to_poset().greene_kleitman();
+		assert_eq!(Diagram::from(lambda.clone()), word.to_tableau().shape());
+		assert_eq!(lambda.iter().take(2).sum::<usize>(), word.L(2));
+	}
+}
 impl Mul for Word {
 	type Output = Self;
     fn mul(mut self, mut rhs: Self) -> Self {